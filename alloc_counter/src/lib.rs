@@ -0,0 +1,132 @@
+/* a global allocator counting the heap allocations of the current thread,
+   to observe what the examples really allocate:
+
+       let (text, allocations) = alloc_counter::measure(|| String::from("text"));
+       println!("{}", allocations); // allocations: 1, deallocations: 0, bytes: 4
+
+   only one global allocator can be declared into a program,
+   so the examples share this one;
+   the counters are per thread, other threads do not change the measures */
+
+use std::alloc::{
+    GlobalAlloc,
+    Layout,
+    System,
+};
+use std::cell::Cell;
+use std::fmt;
+use std::thread::LocalKey;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static DEALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static BYTES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Adds the amount to the counter of the current thread
+fn count(
+    counter: &'static LocalKey<Cell<usize>>,
+    amount: usize,
+) {
+    /* the counters are not available anymore while the thread is destroyed,
+       the allocations of this moment are not counted */
+    let _ = counter.try_with(|counter| counter.set(counter.get() + amount));
+}
+
+/// Forwards to the system allocator, and counts
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+
+    unsafe fn alloc(
+        &self,
+        layout: Layout,
+    ) -> *mut u8
+    {
+        count(&ALLOCATIONS, 1);
+        count(&BYTES, layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(
+        &self,
+        pointer: *mut u8,
+        layout: Layout,
+    ) {
+        count(&DEALLOCATIONS, 1);
+        System.dealloc(pointer, layout)
+    }
+
+    /* a reallocation is counted as a new allocation and a deallocation */
+    unsafe fn realloc(
+        &self,
+        pointer: *mut u8,
+        layout: Layout,
+        size: usize,
+    ) -> *mut u8
+    {
+        count(&ALLOCATIONS, 1);
+        count(&DEALLOCATIONS, 1);
+        count(&BYTES, size);
+        System.realloc(pointer, layout, size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Heap activity of the current thread
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Allocations {
+
+    pub allocations: usize,
+
+    pub deallocations: usize,
+
+    /// Amount of allocated bytes, freed bytes are not subtracted
+    pub bytes: usize,
+}
+
+/// Returns the heap activity of the current thread since it started
+pub fn current() -> Allocations {
+    Allocations {
+        allocations: ALLOCATIONS.with(Cell::get),
+        deallocations: DEALLOCATIONS.with(Cell::get),
+        bytes: BYTES.with(Cell::get),
+    }
+}
+
+/// Calls the function, returns its result
+/// and what it allocated and freed on the current thread
+pub fn measure<T, F: FnOnce() -> T>(function: F) -> (T, Allocations) {
+
+    let before = current();
+    let result = function();
+    let after = current();
+
+    (
+        result,
+        Allocations {
+            allocations: after.allocations - before.allocations,
+            deallocations: after.deallocations - before.deallocations,
+            bytes: after.bytes - before.bytes,
+        },
+    )
+}
+
+impl fmt::Display for Allocations {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        write!(
+            formatter,
+            "allocations: {}, deallocations: {}, bytes: {}",
+            self.allocations,
+            self.deallocations,
+            self.bytes,
+        )
+    }
+}
@@ -0,0 +1,77 @@
+use std::hint::black_box;
+use std::thread;
+
+use alloc_counter::{
+    measure,
+    Allocations,
+};
+
+#[test]
+fn stack_values_are_not_allocated() {
+
+    let (value, allocations) = measure(|| black_box([0u8; 64]));
+    assert_eq!(value.len(), 64);
+    assert_eq!(allocations, Allocations::default());
+}
+
+#[test]
+fn boxes_are_allocated_and_freed() {
+
+    let (_, allocations) = measure(|| {
+        let boxed = black_box(Box::new(10u64));
+        drop(boxed);
+    });
+
+    assert_eq!(
+        allocations,
+        Allocations {
+            allocations: 1,
+            deallocations: 1,
+            bytes: 8,
+        },
+    );
+}
+
+#[test]
+fn reallocations_are_counted() {
+
+    let (vector, allocations) = measure(|| {
+        let mut vector: Vec<u8> = Vec::with_capacity(4);
+        vector.extend_from_slice(&[0; 8]);
+        vector
+    });
+
+    assert_eq!(vector.len(), 8);
+    assert_eq!(allocations.allocations, 2);
+    assert_eq!(allocations.deallocations, 1);
+    assert!(allocations.bytes >= 12);
+}
+
+#[test]
+fn other_threads_are_not_measured() {
+
+    let (_, allocations) = measure(|| {
+        thread::scope(|scope| {
+            scope.spawn(|| black_box(vec![0u8; 1024]));
+        });
+    });
+
+    /* the allocations of the spawned thread itself are on this thread,
+       its vector is not */
+    assert!(allocations.bytes < 1024);
+}
+
+#[test]
+fn measures_can_be_nested() {
+
+    let ((_, inner), outer) = measure(|| {
+        let first = black_box(String::from("first"));
+        let second = measure(|| black_box(String::from("second")));
+        /* the second string is dropped here */
+        (first, second.1)
+    });
+
+    assert_eq!(inner.allocations, 1);
+    assert_eq!(outer.allocations, 2);
+    assert_eq!(outer.to_string(), "allocations: 2, deallocations: 1, bytes: 11");
+}
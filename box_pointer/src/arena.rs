@@ -0,0 +1,598 @@
+/* an alternative to one Box per node: every node is stored into one vector,
+   the arena, and the nodes refer to each other by their index;
+
+   the nodes are allocated with a few reallocations of the vector
+   instead of one allocation per node, they are next to each other in memory
+   (faster to traverse), and they are all freed at once with the arena;
+   a node freed alone is replaced by the last node of the vector,
+   so the handles of that last node must be updated, and an index of another arena
+   is a logic error the compiler cannot catch */
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+
+/// Handle of a node of an `Arena<T>`, typed so an index of an arena
+/// of another type of nodes cannot be used by mistake
+pub struct NodeId<T> {
+    index: usize,
+    node_type: PhantomData<fn() -> T>,
+}
+
+/* derived traits would require T to implement them too */
+impl<T> Clone for NodeId<T> {
+
+    fn clone(&self) -> NodeId<T> {
+        *self
+    }
+}
+
+impl<T> Copy for NodeId<T> {}
+
+impl<T> PartialEq for NodeId<T> {
+
+    fn eq(
+        &self,
+        other: &NodeId<T>,
+    ) -> bool
+    {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for NodeId<T> {}
+
+impl<T> fmt::Debug for NodeId<T> {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        write!(formatter, "NodeId({})", self.index)
+    }
+}
+
+pub struct Arena<T> {
+    nodes: Vec<T>,
+}
+
+impl<T> Arena<T> {
+
+    pub fn new() -> Arena<T> {
+        Arena {
+            nodes: Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena {
+            nodes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Stores the node into the arena, returns its handle
+    pub fn alloc(
+        &mut self,
+        node: T,
+    ) -> NodeId<T>
+    {
+        self.nodes.push(node);
+
+        NodeId {
+            index: self.nodes.len() - 1,
+            node_type: PhantomData,
+        }
+    }
+
+    pub fn get(
+        &self,
+        id: NodeId<T>,
+    ) -> &T
+    {
+        &self.nodes[id.index]
+    }
+
+    pub fn get_mut(
+        &mut self,
+        id: NodeId<T>,
+    ) -> &mut T
+    {
+        &mut self.nodes[id.index]
+    }
+
+    /// Takes the node out of the arena, the last node is moved into its place:
+    /// the handles of the last node now refer to the given handle
+    pub fn swap_remove(
+        &mut self,
+        id: NodeId<T>,
+    ) -> T
+    {
+        self.nodes.swap_remove(id.index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl<T> Default for Arena<T> {
+
+    fn default() -> Arena<T> {
+        Arena::new()
+    }
+}
+
+pub struct ListNode<T> {
+    value: T,
+    next: Option<NodeId<ListNode<T>>>,
+}
+
+/// The recursive list of the list module, the Box of the next node
+/// replaced by its handle; there is no recursive drop to worry about
+pub struct ArenaList<T> {
+    arena: Arena<ListNode<T>>,
+    head: Option<NodeId<ListNode<T>>>,
+}
+
+impl<T> ArenaList<T> {
+
+    pub fn new() -> ArenaList<T> {
+        ArenaList {
+            arena: Arena::new(),
+            head: None,
+        }
+    }
+
+    /// Adds the value at the front of the list
+    pub fn push(
+        &mut self,
+        value: T,
+    ) {
+        let next = self.head;
+        self.head = Some(self.arena.alloc(ListNode { value, next }));
+    }
+
+    /// Removes the value at the front of the list
+    pub fn pop(&mut self) -> Option<T> {
+
+        let head = self.head?;
+        let last = NodeId {
+            index: self.arena.len() - 1,
+            node_type: PhantomData,
+        };
+
+        let node = self.arena.swap_remove(head);
+        self.head = node.next;
+
+        /* the last node of the arena takes the place of the head:
+           the link to it is updated (the head is the last pushed node,
+           so usually nothing moves) */
+        if last != head {
+            if self.head == Some(last) {
+                self.head = Some(head);
+            } else {
+                let mut link = self.head;
+                while let Some(id) = link {
+                    let node = self.arena.get_mut(id);
+                    if node.next == Some(last) {
+                        node.next = Some(head);
+                        break;
+                    }
+                    link = node.next;
+                }
+            }
+        }
+
+        Some(node.value)
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.map(|head| &self.arena.get(head).value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.arena.is_empty()
+    }
+
+    pub fn iter(&self) -> ArenaListIter<'_, T> {
+        ArenaListIter {
+            arena: &self.arena,
+            next: self.head,
+        }
+    }
+}
+
+impl<T> Default for ArenaList<T> {
+
+    fn default() -> ArenaList<T> {
+        ArenaList::new()
+    }
+}
+
+/// Borrows the values of the list, from the front to the back
+pub struct ArenaListIter<'a, T> {
+    arena: &'a Arena<ListNode<T>>,
+    next: Option<NodeId<ListNode<T>>>,
+}
+
+impl<'a, T> Iterator for ArenaListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+
+        let node = self.arena.get(self.next?);
+        self.next = node.next;
+        Some(&node.value)
+    }
+}
+
+pub struct TreeNode<K, V> {
+    key: K,
+    value: V,
+    height: usize,
+    left: Option<NodeId<TreeNode<K, V>>>,
+    right: Option<NodeId<TreeNode<K, V>>>,
+}
+
+type TreeLink<K, V> = Option<NodeId<TreeNode<K, V>>>;
+
+/// The balanced binary search tree of the tree module, the boxes of the children
+/// replaced by their handles: a rotation changes indices, no node is moved
+pub struct ArenaTree<K, V> {
+    arena: Arena<TreeNode<K, V>>,
+    root: TreeLink<K, V>,
+}
+
+impl<K: Ord, V> ArenaTree<K, V> {
+
+    pub fn new() -> ArenaTree<K, V> {
+        ArenaTree {
+            arena: Arena::new(),
+            root: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.arena.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.arena.is_empty()
+    }
+
+    fn height_of(
+        &self,
+        link: TreeLink<K, V>,
+    ) -> usize
+    {
+        link.map_or(0, |id| self.arena.get(id).height)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height_of(self.root)
+    }
+
+    fn update_height(
+        &mut self,
+        id: NodeId<TreeNode<K, V>>,
+    ) {
+        let node = self.arena.get(id);
+        let height = 1 + self.height_of(node.left).max(self.height_of(node.right));
+        self.arena.get_mut(id).height = height;
+    }
+
+    fn balance(
+        &self,
+        id: NodeId<TreeNode<K, V>>,
+    ) -> isize
+    {
+        let node = self.arena.get(id);
+        self.height_of(node.left) as isize - self.height_of(node.right) as isize
+    }
+
+    fn rotate_left(
+        &mut self,
+        id: NodeId<TreeNode<K, V>>,
+    ) -> NodeId<TreeNode<K, V>>
+    {
+        let Some(right) = self.arena.get(id).right else {
+            return id;
+        };
+
+        self.arena.get_mut(id).right = self.arena.get(right).left;
+        self.update_height(id);
+
+        self.arena.get_mut(right).left = Some(id);
+        self.update_height(right);
+        right
+    }
+
+    fn rotate_right(
+        &mut self,
+        id: NodeId<TreeNode<K, V>>,
+    ) -> NodeId<TreeNode<K, V>>
+    {
+        let Some(left) = self.arena.get(id).left else {
+            return id;
+        };
+
+        self.arena.get_mut(id).left = self.arena.get(left).right;
+        self.update_height(id);
+
+        self.arena.get_mut(left).right = Some(id);
+        self.update_height(left);
+        left
+    }
+
+    fn rebalance(
+        &mut self,
+        id: NodeId<TreeNode<K, V>>,
+    ) -> NodeId<TreeNode<K, V>>
+    {
+        self.update_height(id);
+
+        if self.balance(id) > 1 {
+            if let Some(left) = self.arena.get(id).left.filter(|left| self.balance(*left) < 0) {
+                self.arena.get_mut(id).left = Some(self.rotate_left(left));
+            }
+            return self.rotate_right(id);
+        }
+
+        if self.balance(id) < -1 {
+            if let Some(right) = self.arena.get(id).right.filter(|right| self.balance(*right) > 0) {
+                self.arena.get_mut(id).right = Some(self.rotate_right(right));
+            }
+            return self.rotate_left(id);
+        }
+
+        id
+    }
+
+    fn insert_into(
+        &mut self,
+        link: TreeLink<K, V>,
+        key: K,
+        value: V,
+    ) -> (NodeId<TreeNode<K, V>>, Option<V>)
+    {
+        let Some(id) = link else {
+            let node = TreeNode {
+                key,
+                value,
+                height: 1,
+                left: None,
+                right: None,
+            };
+            return (self.arena.alloc(node), None);
+        };
+
+        let node = self.arena.get(id);
+        let previous = match key.cmp(&node.key) {
+            Ordering::Less => {
+                let (left, previous) = self.insert_into(node.left, key, value);
+                self.arena.get_mut(id).left = Some(left);
+                previous
+            }
+            Ordering::Greater => {
+                let (right, previous) = self.insert_into(node.right, key, value);
+                self.arena.get_mut(id).right = Some(right);
+                previous
+            }
+            Ordering::Equal => Some(mem::replace(&mut self.arena.get_mut(id).value, value)),
+        };
+
+        (self.rebalance(id), previous)
+    }
+
+    /// Detaches the smallest node of the subtree,
+    /// returns the rest of the subtree and the detached node
+    fn remove_min(
+        &mut self,
+        id: NodeId<TreeNode<K, V>>,
+    ) -> (TreeLink<K, V>, NodeId<TreeNode<K, V>>)
+    {
+        let node = self.arena.get(id);
+        match node.left {
+            Some(left) => {
+                let (rest, min) = self.remove_min(left);
+                self.arena.get_mut(id).left = rest;
+                (Some(self.rebalance(id)), min)
+            }
+            None => (node.right, id),
+        }
+    }
+
+    /// Detaches the node of the key from the subtree,
+    /// returns the new root of the subtree and the detached node;
+    /// the node stays into the arena, so the handles of the callers remain valid
+    fn remove_from(
+        &mut self,
+        link: TreeLink<K, V>,
+        key: &K,
+    ) -> (TreeLink<K, V>, TreeLink<K, V>)
+    {
+        let Some(id) = link else {
+            return (None, None);
+        };
+
+        let node = self.arena.get(id);
+        match key.cmp(&node.key) {
+            Ordering::Less => {
+                let (left, removed) = self.remove_from(node.left, key);
+                self.arena.get_mut(id).left = left;
+                (Some(self.rebalance(id)), removed)
+            }
+            Ordering::Greater => {
+                let (right, removed) = self.remove_from(node.right, key);
+                self.arena.get_mut(id).right = right;
+                (Some(self.rebalance(id)), removed)
+            }
+            Ordering::Equal => {
+
+                /* the node is replaced by the smallest node of its right subtree,
+                   only the handles change, no node is moved */
+                let root = match (node.left, node.right) {
+                    (None, None) => None,
+                    (Some(child), None) | (None, Some(child)) => Some(child),
+                    (Some(left), Some(right)) => {
+                        let (rest, successor) = self.remove_min(right);
+                        let node = self.arena.get_mut(successor);
+                        node.left = Some(left);
+                        node.right = rest;
+                        Some(self.rebalance(successor))
+                    }
+                };
+
+                (root, Some(id))
+            }
+        }
+    }
+
+    /// Frees the detached node: the last node of the arena takes its place,
+    /// so the link to the last node (found by its key) is updated
+    fn free(
+        &mut self,
+        id: NodeId<TreeNode<K, V>>,
+    ) -> V
+    {
+        let last = NodeId {
+            index: self.arena.len() - 1,
+            node_type: PhantomData,
+        };
+        let removed = self.arena.swap_remove(id);
+
+        if last != id {
+
+            /* the parent of the moved node is on the path of its key */
+            let key = &self.arena.get(id).key;
+            let mut parent = None;
+            let mut link = self.root;
+
+            while link != Some(last) {
+                let Some(node_id) = link else {
+                    unreachable!("the moved node is into the tree");
+                };
+                let node = self.arena.get(node_id);
+                parent = Some(node_id);
+                link = match key.cmp(&node.key) {
+                    Ordering::Less => node.left,
+                    _ => node.right,
+                };
+            }
+
+            match parent {
+                None => self.root = Some(id),
+                Some(parent) => {
+                    let parent = self.arena.get_mut(parent);
+                    if parent.left == Some(last) {
+                        parent.left = Some(id);
+                    } else {
+                        parent.right = Some(id);
+                    }
+                }
+            }
+        }
+
+        removed.value
+    }
+
+    /// Inserts the value, returns the previous value of the key
+    pub fn insert(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Option<V>
+    {
+        let (root, previous) = self.insert_into(self.root, key, value);
+        self.root = Some(root);
+        previous
+    }
+
+    /// Removes the key, returns its value
+    pub fn remove(
+        &mut self,
+        key: &K,
+    ) -> Option<V>
+    {
+        let (root, removed) = self.remove_from(self.root, key);
+        self.root = root;
+        removed.map(|id| self.free(id))
+    }
+
+    pub fn get(
+        &self,
+        key: &K,
+    ) -> Option<&V>
+    {
+        let mut link = self.root;
+
+        while let Some(id) = link {
+            let node = self.arena.get(id);
+            link = match key.cmp(&node.key) {
+                Ordering::Less => node.left,
+                Ordering::Greater => node.right,
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+
+        None
+    }
+
+    /// Iterates over the nodes sorted by key
+    pub fn iter(&self) -> ArenaTreeIter<'_, K, V> {
+
+        let mut iterator = ArenaTreeIter {
+            arena: &self.arena,
+            stack: Vec::new(),
+        };
+        iterator.push_left_branch(self.root);
+        iterator
+    }
+}
+
+impl<K: Ord, V> Default for ArenaTree<K, V> {
+
+    fn default() -> ArenaTree<K, V> {
+        ArenaTree::new()
+    }
+}
+
+/// Iterates over the nodes of the tree sorted by key
+pub struct ArenaTreeIter<'a, K, V> {
+    arena: &'a Arena<TreeNode<K, V>>,
+    stack: Vec<NodeId<TreeNode<K, V>>>,
+}
+
+impl<K, V> ArenaTreeIter<'_, K, V> {
+
+    fn push_left_branch(
+        &mut self,
+        mut link: TreeLink<K, V>,
+    ) {
+        while let Some(id) = link {
+            self.stack.push(id);
+            link = self.arena.get(id).left;
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for ArenaTreeIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+
+        let node = self.arena.get(self.stack.pop()?);
+        self.push_left_branch(node.right);
+        Some((&node.key, &node.value))
+    }
+}
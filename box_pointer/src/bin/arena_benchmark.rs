@@ -0,0 +1,135 @@
+/* compares the structures built with one Box per node
+   (list and tree modules) with the same structures built into an arena:
+   amount of allocations, build time and traversal time
+   for 10^3 to 10^7 nodes
+
+   `cargo run --release -p box_pointer --bin arena_benchmark -- [max power of ten]`,
+   the default max power of ten is 7 */
+
+use std::env;
+use std::hint::black_box;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use box_pointer::arena::{
+    ArenaList,
+    ArenaTree,
+};
+use box_pointer::list::List;
+use box_pointer::tree::BinarySearchTree;
+
+const DEFAULT_MAX_POWER: u32 = 7;
+
+/// Result of the benchmark of one structure
+struct Measure {
+    allocations: usize,
+    build: Duration,
+    traversal: Duration,
+}
+
+/// Builds the structure, then traverses it
+fn measure<S, B: FnOnce() -> S, T: FnOnce(&S) -> u64>(
+    build: B,
+    traverse: T,
+) -> Measure
+{
+    /* the reallocations of the arena vector are counted as allocations */
+    let start = Instant::now();
+    let (structure, allocations) = alloc_counter::measure(build);
+    let build_time = start.elapsed();
+
+    let start = Instant::now();
+    black_box(traverse(&structure));
+    let traversal = start.elapsed();
+
+    Measure {
+        allocations: allocations.allocations,
+        build: build_time,
+        traversal,
+    }
+}
+
+/* pseudo-random keys without dependency (linear congruential generator),
+   sorted keys would be the worst case of an unbalanced tree */
+fn random_keys(count: usize) -> Vec<u32> {
+
+    let mut state: u64 = 42;
+    (0..count)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 32) as u32
+        })
+        .collect()
+}
+
+fn print_measure(
+    structure: &str,
+    nodes: usize,
+    measure: Measure,
+) {
+    println!(
+        "{:<12} {:>10} {:>12} {:>12.2?} {:>12.2?}",
+        structure,
+        nodes,
+        measure.allocations,
+        measure.build,
+        measure.traversal,
+    );
+}
+
+fn main() {
+
+    let max_power = env::args()
+        .nth(1)
+        .and_then(|power| power.parse().ok())
+        .unwrap_or(DEFAULT_MAX_POWER);
+
+    println!(
+        "{:<12} {:>10} {:>12} {:>12} {:>12}",
+        "structure", "nodes", "allocations", "build", "traversal",
+    );
+
+    for power in 3..=max_power {
+
+        let nodes = 10usize.pow(power);
+        let keys = random_keys(nodes);
+
+        let box_list = measure(
+            || keys.iter().copied().collect::<List<u32>>(),
+            |list| list.iter().map(|value| *value as u64).sum(),
+        );
+        print_measure("Box list", nodes, box_list);
+
+        let arena_list = measure(
+            || {
+                let mut list = ArenaList::new();
+                for key in &keys {
+                    list.push(*key);
+                }
+                list
+            },
+            |list| list.iter().map(|value| *value as u64).sum(),
+        );
+        print_measure("arena list", nodes, arena_list);
+
+        let box_tree = measure(
+            || keys.iter().map(|key| (*key, *key)).collect::<BinarySearchTree<u32, u32>>(),
+            |tree| tree.iter().map(|(_, value)| *value as u64).sum(),
+        );
+        print_measure("Box tree", nodes, box_tree);
+
+        let arena_tree = measure(
+            || {
+                let mut tree = ArenaTree::new();
+                for key in &keys {
+                    tree.insert(*key, *key);
+                }
+                tree
+            },
+            |tree| tree.iter().map(|(_, value)| *value as u64).sum(),
+        );
+        print_measure("arena tree", nodes, arena_tree);
+    }
+}
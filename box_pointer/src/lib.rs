@@ -0,0 +1,158 @@
+pub mod arena;
+pub mod list;
+pub mod tree;
+
+use alloc_counter::measure;
+use arena::ArenaList;
+use list::{
+    List,
+    RecursiveList,
+};
+use tree::BinarySearchTree;
+
+/* valid recursive structure declaration:
+   `next: Box<RecursiveStructure>` would compile too,
+   but could never be created as there would always be a next one;
+   None ends the recursion */
+struct RecursiveStructure {
+    next: Option<Box<RecursiveStructure>>,
+    value: u8,
+}
+
+struct MyStructure {
+    first: u32,
+    second: bool,
+}
+
+#[allow(unused)]
+#[derive(Clone)]
+struct MyOtherStructure {
+    first: u8,
+    second: u8,
+}
+
+#[allow(unused)]
+pub fn run() {
+
+    /* 10 is allocated on the heap;
+       there is no need to use the dereference
+       pointer (*) to get the pointed data;
+
+       `measure` returns what has been allocated on the heap
+       by the given closure: one allocation of 4 bytes (an i32) */
+    let (pointer, allocations) = measure(|| Box::new(10));
+    println!("Value: {}", pointer);
+    println!("{}", allocations); // allocations: 1, deallocations: 0, bytes: 4
+
+    /* the structure is allocated on the stack: no allocation */
+    let (object, allocations) = measure(|| MyStructure {
+        first: 10,
+        second: true,
+    });
+    println!("{}", object.first);
+    println!("{}", object.second);
+    println!("{}", allocations); // allocations: 0, deallocations: 0, bytes: 0
+
+    /* the structure is allocated on the heap:
+       one allocation of 8 bytes (an u32, a bool and the padding) */
+    let (object, allocations) = measure(|| Box::new(
+        MyStructure {
+            first: 10,
+            second: false,
+        }
+    ));
+    println!("{}", object.first);
+    println!("{}", object.second);
+    println!("{}", allocations); // allocations: 1, deallocations: 0, bytes: 8
+
+    /* known way to create recursive list */
+    let list = RecursiveList::Next(
+        10,
+        Box::new(
+            RecursiveList::Next(
+                20,
+                Box::new(RecursiveList::End)
+            )
+        )
+    );
+
+    /* the list module wraps the recursive enumeration:
+       values are pushed and popped at the front */
+    let mut list: List<u8> = (1..=3).collect();
+    list.push(0);
+    println!("{}", list); // 0 -> 1 -> 2 -> 3 -> End
+    println!("{}", list.len()); // 4
+
+    list.reverse();
+    println!("{}", list); // 3 -> 2 -> 1 -> 0 -> End
+
+    /* the list is dropped node by node, without recursion,
+       so even a very long list does not overflow the stack */
+    let long: List<u32> = (0..1_000_000).collect();
+    println!("{}", long.len()); // 1000000
+
+    /* the optional Box allows to create the recursive structure */
+    let structure = RecursiveStructure {
+        value: 10,
+        next: Some(
+            Box::new(
+                RecursiveStructure {
+                    value: 20,
+                    next: None,
+                }
+            )
+        ),
+    };
+    println!("{}", structure.next.map_or(0, |next| next.value)); // 20
+
+    /* a binary search tree is built the same way,
+       every node has two optional boxed children;
+       the boxes are moved into other nodes when the tree is rotated */
+    let mut tree = BinarySearchTree::new();
+    for key in 1..=7 {
+        tree.insert(key, key * 10);
+    }
+    println!("{}", tree.height()); // 3
+
+    /* the sorted keys were rotated to keep the tree balanced: 4 is the root */
+    let roots: Vec<u8> = tree.pre_order().map(|(key, _)| *key).collect();
+    println!("{:?}", roots); // [4, 2, 1, 3, 6, 5, 7]
+
+    tree.remove(&4);
+    let values: Vec<u8> = tree.range(2..6).map(|(_, value)| *value).collect();
+    println!("{:?}", values); // [20, 30, 50]
+
+    /* the nodes can also be stored into one vector (an arena)
+       and refer to each other by index instead of by Box:
+       one allocation per growth of the vector instead of one per node;
+       a node freed alone is replaced by the last node of the vector */
+    let mut arena_list = ArenaList::new();
+    for value in 1..=3 {
+        arena_list.push(value);
+    }
+    println!("{:?}", arena_list.iter().collect::<Vec<_>>()); // [3, 2, 1]
+    println!("{:?}", arena_list.pop()); // Some(3)
+
+    /* a Box<T> is moved by default */
+    let first = Box::new(
+        MyStructure {
+            first: 100,
+            second: false,
+        }
+    );
+    let second = first;
+    println!("{}", second.first);
+    //error: println!("{}", first.first);
+
+    /* a Box<T> can be copied only
+       if the boxed structure implements Clone */
+    let first = Box::new(
+        MyOtherStructure {
+            first: 10,
+            second: 20,
+        }
+    );
+    let second = first.clone();
+    println!("{}", first.first);
+    println!("{}", second.first);
+}
@@ -0,0 +1,207 @@
+/* a singly-linked list built on the recursive enumeration:
+   every node owns the next one through a Box<T> */
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::mem;
+
+/* the size of a recursive type must be known at compile time,
+   so the next node is boxed: the enumeration only contains a pointer */
+pub enum RecursiveList<T> {
+    Next(
+        T,
+        Box<RecursiveList<T>>,
+    ),
+    End,
+}
+
+pub struct List<T> {
+    head: RecursiveList<T>,
+    length: usize,
+}
+
+impl<T> List<T> {
+
+    pub fn new() -> List<T> {
+        List {
+            head: RecursiveList::End,
+            length: 0,
+        }
+    }
+
+    /// Adds the value at the front of the list
+    pub fn push(
+        &mut self,
+        value: T,
+    ) {
+        /* the current head is moved into the new node,
+           End is put in its place meanwhile */
+        let next = mem::replace(&mut self.head, RecursiveList::End);
+        self.head = RecursiveList::Next(value, Box::new(next));
+        self.length += 1;
+    }
+
+    /// Removes the value at the front of the list
+    pub fn pop(&mut self) -> Option<T> {
+
+        match mem::replace(&mut self.head, RecursiveList::End) {
+            RecursiveList::Next(value, next) => {
+                self.head = *next;
+                self.length -= 1;
+                Some(value)
+            }
+            RecursiveList::End => None,
+        }
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+
+        match &self.head {
+            RecursiveList::Next(value, _) => Some(value),
+            RecursiveList::End => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Reverses the list in place, the boxes are relinked one by one
+    pub fn reverse(&mut self) {
+
+        let mut reversed = RecursiveList::End;
+        let mut current = mem::replace(&mut self.head, RecursiveList::End);
+
+        /* the box of the node is reused: its content (the rest of the list)
+           is replaced by the already reversed nodes */
+        while let RecursiveList::Next(value, mut next) = current {
+            current = mem::replace(&mut *next, reversed);
+            reversed = RecursiveList::Next(value, next);
+        }
+
+        self.head = reversed;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: &self.head,
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+
+    fn default() -> List<T> {
+        List::new()
+    }
+}
+
+/* the default drop is recursive: every node drops its Box,
+   that drops the next node... and a long list overflows the stack;
+   the nodes are dropped one by one instead */
+impl<T> Drop for List<T> {
+
+    fn drop(&mut self) {
+
+        let mut current = mem::replace(&mut self.head, RecursiveList::End);
+
+        /* the next node is moved out of the Box before the node is dropped,
+           so the dropped node has nothing left to drop recursively */
+        while let RecursiveList::Next(_, mut next) = current {
+            current = mem::replace(&mut *next, RecursiveList::End);
+        }
+    }
+}
+
+/// Borrows the values of the list, from the front to the back
+pub struct Iter<'a, T> {
+    next: &'a RecursiveList<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+
+        match self.next {
+            RecursiveList::Next(value, next) => {
+                self.next = next;
+                Some(value)
+            }
+            RecursiveList::End => None,
+        }
+    }
+}
+
+/// Moves the values out of the list, from the front to the back
+pub struct IntoIter<T> {
+    list: List<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            list: self,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/* the values are pushed at the front, so the list is reversed
+   at the end to keep the order of the iterator */
+impl<T> FromIterator<T> for List<T> {
+
+    fn from_iter<I: IntoIterator<Item = T>>(iterator: I) -> List<T> {
+
+        let mut list = List::new();
+        for value in iterator {
+            list.push(value);
+        }
+
+        list.reverse();
+        list
+    }
+}
+
+/* displayed as the chain of nodes: "1 -> 2 -> End" */
+impl<T: fmt::Display> fmt::Display for List<T> {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        for value in self {
+            write!(formatter, "{} -> ", value)?;
+        }
+
+        write!(formatter, "End")
+    }
+}
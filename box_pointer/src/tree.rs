@@ -0,0 +1,420 @@
+/* a binary search tree built with Option<Box<T>>:
+   None is the base case of the recursion (no child),
+   every node owns its children through their Box;
+
+   the tree is balanced (AVL): when the heights of the two children
+   of a node differ by more than one, the node is rotated,
+   the boxes are moved from a parent to another without copying the nodes */
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{
+    Bound,
+    RangeBounds,
+    RangeFull,
+};
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    height: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+/// Returns the height of the subtree, 0 if there is no node
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+impl<K, V> Node<K, V> {
+
+    fn new(
+        key: K,
+        value: V,
+    ) -> Box<Node<K, V>>
+    {
+        Box::new(Node {
+            key,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        })
+    }
+
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    /// Left height minus right height
+    fn balance(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+/* the node takes the place of its right child:
+
+       node               right
+       /  \               /   \
+      a   right   ->   node    c
+          /   \        /  \
+         b     c      a    b
+
+   the boxes are moved: the node is moved into its right child,
+   that is returned as the new root of the subtree */
+fn rotate_left<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+
+    let Some(mut right) = node.right.take() else {
+        return node;
+    };
+
+    node.right = right.left.take();
+    node.update_height();
+
+    right.left = Some(node);
+    right.update_height();
+    right
+}
+
+/* the opposite of the left rotation */
+fn rotate_right<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+
+    let Some(mut left) = node.left.take() else {
+        return node;
+    };
+
+    node.left = left.right.take();
+    node.update_height();
+
+    left.right = Some(node);
+    left.update_height();
+    left
+}
+
+/// Rotates the node if its children heights differ by more than one,
+/// returns the new root of the subtree
+fn rebalance<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+
+    node.update_height();
+
+    if node.balance() > 1 {
+
+        /* the left child leans to the right: rotated first */
+        if node.left.as_ref().is_some_and(|left| left.balance() < 0) {
+            node.left = node.left.take().map(rotate_left);
+        }
+        return rotate_right(node);
+    }
+
+    if node.balance() < -1 {
+
+        if node.right.as_ref().is_some_and(|right| right.balance() > 0) {
+            node.right = node.right.take().map(rotate_right);
+        }
+        return rotate_left(node);
+    }
+
+    node
+}
+
+/// Inserts the value into the subtree, returns its new root
+/// and the previous value of the key
+fn insert<K: Ord, V>(
+    link: Link<K, V>,
+    key: K,
+    value: V,
+) -> (Box<Node<K, V>>, Option<V>)
+{
+    let Some(mut node) = link else {
+        return (Node::new(key, value), None);
+    };
+
+    let previous = match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, previous) = insert(node.left.take(), key, value);
+            node.left = Some(left);
+            previous
+        }
+        Ordering::Greater => {
+            let (right, previous) = insert(node.right.take(), key, value);
+            node.right = Some(right);
+            previous
+        }
+        Ordering::Equal => Some(mem::replace(&mut node.value, value)),
+    };
+
+    (rebalance(node), previous)
+}
+
+/// Removes the smallest node of the subtree,
+/// returns the rest of the subtree and the removed node
+fn remove_min<K, V>(mut node: Box<Node<K, V>>) -> (Link<K, V>, Box<Node<K, V>>) {
+
+    match node.left.take() {
+        Some(left) => {
+            let (rest, min) = remove_min(left);
+            node.left = rest;
+            (Some(rebalance(node)), min)
+        }
+        None => (node.right.take(), node),
+    }
+}
+
+/// Removes the key from the subtree, returns its new root and the removed value
+fn remove<K: Ord, V>(
+    link: Link<K, V>,
+    key: &K,
+) -> (Link<K, V>, Option<V>)
+{
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+
+    match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), key);
+            node.left = left;
+            (Some(rebalance(node)), removed)
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), key);
+            node.right = right;
+            (Some(rebalance(node)), removed)
+        }
+        Ordering::Equal => {
+
+            let Node { value, left, right, .. } = *node;
+
+            /* the node is replaced by the smallest node of its right subtree */
+            let root = match (left, right) {
+                (None, None) => None,
+                (Some(child), None) | (None, Some(child)) => Some(child),
+                (Some(left), Some(right)) => {
+                    let (rest, mut successor) = remove_min(right);
+                    successor.left = Some(left);
+                    successor.right = rest;
+                    Some(rebalance(successor))
+                }
+            };
+
+            (root, Some(value))
+        }
+    }
+}
+
+pub struct BinarySearchTree<K, V> {
+    root: Link<K, V>,
+    length: usize,
+}
+
+impl<K: Ord, V> BinarySearchTree<K, V> {
+
+    pub fn new() -> BinarySearchTree<K, V> {
+        BinarySearchTree {
+            root: None,
+            length: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Amount of nodes from the root to the deepest leaf, 0 if the tree is empty
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    pub fn get(
+        &self,
+        key: &K,
+    ) -> Option<&V>
+    {
+        let mut link = &self.root;
+
+        while let Some(node) = link {
+            link = match key.cmp(&node.key) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+
+        None
+    }
+
+    pub fn contains_key(
+        &self,
+        key: &K,
+    ) -> bool
+    {
+        self.get(key).is_some()
+    }
+
+    /// Inserts the value, returns the previous value of the key
+    pub fn insert(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Option<V>
+    {
+        /* the root is moved out of the tree, and the new root moved back */
+        let (root, previous) = insert(self.root.take(), key, value);
+        self.root = Some(root);
+
+        if previous.is_none() {
+            self.length += 1;
+        }
+        previous
+    }
+
+    /// Removes the key, returns its value
+    pub fn remove(
+        &mut self,
+        key: &K,
+    ) -> Option<V>
+    {
+        let (root, removed) = remove(self.root.take(), key);
+        self.root = root;
+
+        if removed.is_some() {
+            self.length -= 1;
+        }
+        removed
+    }
+
+    /// Iterates over the nodes sorted by key
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.range(..)
+    }
+
+    /// Iterates over the nodes from the root, every node before its children
+    pub fn pre_order(&self) -> PreOrder<'_, K, V> {
+        PreOrder {
+            stack: self.root.as_deref().into_iter().collect(),
+        }
+    }
+
+    /// Iterates over the nodes whose key is into the range, sorted by key
+    pub fn range<R: RangeBounds<K>>(
+        &self,
+        range: R,
+    ) -> Range<'_, K, V, R>
+    {
+        let mut stack = Vec::new();
+        let mut link = &self.root;
+
+        /* only the nodes after the start of the range are stacked,
+           the subtrees before it are never visited */
+        while let Some(node) = link {
+
+            let after_start = match range.start_bound() {
+                Bound::Included(start) => node.key >= *start,
+                Bound::Excluded(start) => node.key > *start,
+                Bound::Unbounded => true,
+            };
+
+            if after_start {
+                stack.push(&**node);
+                link = &node.left;
+            } else {
+                link = &node.right;
+            }
+        }
+
+        Range {
+            stack,
+            range,
+        }
+    }
+}
+
+impl<K: Ord, V> Default for BinarySearchTree<K, V> {
+
+    fn default() -> BinarySearchTree<K, V> {
+        BinarySearchTree::new()
+    }
+}
+
+/// Iterates over the nodes of a range, sorted by key
+pub struct Range<'a, K, V, R> {
+
+    /* the nodes whose left subtree is already visited, the next one last */
+    stack: Vec<&'a Node<K, V>>,
+
+    range: R,
+}
+
+pub type Iter<'a, K, V> = Range<'a, K, V, RangeFull>;
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> Iterator for Range<'a, K, V, R> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+
+        let node = self.stack.pop()?;
+
+        /* the nodes are after the start, so a node out of the range
+           is after its end, and so are all the next ones */
+        if !self.range.contains(&node.key) {
+            self.stack.clear();
+            return None;
+        }
+
+        let mut link = &node.right;
+        while let Some(child) = link {
+            self.stack.push(child);
+            link = &child.left;
+        }
+
+        Some((&node.key, &node.value))
+    }
+}
+
+/// Iterates over the nodes from the root, every node before its children
+pub struct PreOrder<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Iterator for PreOrder<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+
+        let node = self.stack.pop()?;
+
+        /* the right child is stacked first, so the left one is visited first */
+        self.stack.extend(node.right.as_deref());
+        self.stack.extend(node.left.as_deref());
+
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a BinarySearchTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BinarySearchTree<K, V> {
+
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iterator: I) -> BinarySearchTree<K, V> {
+
+        let mut tree = BinarySearchTree::new();
+        for (key, value) in iterator {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
@@ -0,0 +1,140 @@
+use box_pointer::arena::{
+    Arena,
+    ArenaList,
+    ArenaTree,
+};
+use box_pointer::list::List;
+use box_pointer::tree::BinarySearchTree;
+
+#[test]
+fn handles_refer_to_their_node() {
+
+    let mut arena = Arena::new();
+    let first = arena.alloc("first");
+    let second = arena.alloc("second");
+
+    assert_ne!(first, second);
+    assert_eq!(*arena.get(second), "second");
+
+    *arena.get_mut(first) = "changed";
+    assert_eq!(*arena.get(first), "changed");
+    assert_eq!(arena.len(), 2);
+}
+
+#[test]
+fn arena_list_matches_the_boxed_list() {
+
+    let mut arena_list = ArenaList::new();
+    let mut box_list = List::new();
+    assert_eq!(arena_list.peek(), None);
+
+    for value in 0..100 {
+        arena_list.push(value);
+        box_list.push(value);
+    }
+
+    assert_eq!(arena_list.len(), box_list.len());
+    assert_eq!(arena_list.peek(), Some(&99));
+    assert!(arena_list.iter().eq(box_list.iter()));
+
+    for _ in 0..50 {
+        assert_eq!(arena_list.pop(), box_list.pop());
+    }
+    assert_eq!(arena_list.len(), 50);
+    assert_eq!(arena_list.peek(), Some(&49));
+    assert!(arena_list.iter().eq(box_list.iter()));
+
+    /* pushes after pops reuse the end of the arena */
+    arena_list.push(100);
+    box_list.push(100);
+    assert!(arena_list.iter().eq(box_list.iter()));
+
+    while let Some(value) = box_list.pop() {
+        assert_eq!(arena_list.pop(), Some(value));
+    }
+    assert_eq!(arena_list.pop(), None);
+    assert!(arena_list.is_empty());
+}
+
+#[test]
+fn arena_tree_matches_the_boxed_tree() {
+
+    let mut arena_tree = ArenaTree::new();
+    let mut box_tree = BinarySearchTree::new();
+
+    for key in (0..1000u32).map(|key| key * 7919 % 1000) {
+        assert_eq!(arena_tree.insert(key, key * 2), box_tree.insert(key, key * 2));
+    }
+    assert_eq!(arena_tree.insert(10, 0), Some(20));
+    box_tree.insert(10, 0);
+
+    assert_eq!(arena_tree.len(), box_tree.len());
+    assert_eq!(arena_tree.height(), box_tree.height());
+    assert_eq!(arena_tree.get(&10), Some(&0));
+    assert_eq!(arena_tree.get(&1000), None);
+    assert!(arena_tree.iter().eq(box_tree.iter()));
+}
+
+#[test]
+fn sorted_inserts_are_balanced_into_the_arena() {
+
+    let mut tree = ArenaTree::new();
+    for key in 1..1024 {
+        tree.insert(key, ());
+    }
+
+    assert_eq!(tree.height(), 10);
+    assert!(tree.iter().map(|(key, _)| *key).eq(1..1024));
+}
+
+#[test]
+fn a_removed_node_is_replaced_by_the_last_one() {
+
+    let mut arena = Arena::new();
+    let first = arena.alloc("first");
+    let second = arena.alloc("second");
+    arena.alloc("third");
+
+    assert_eq!(arena.swap_remove(first), "first");
+
+    /* the handle of the removed node now refers to the moved node */
+    assert_eq!(*arena.get(first), "third");
+    assert_eq!(*arena.get(second), "second");
+    assert_eq!(arena.len(), 2);
+}
+
+#[test]
+fn arena_tree_removals_match_the_boxed_tree() {
+
+    let mut arena_tree = ArenaTree::new();
+    let mut box_tree = BinarySearchTree::new();
+
+    for key in (0..1000u32).map(|key| key * 7919 % 1000) {
+        arena_tree.insert(key, key * 2);
+        box_tree.insert(key, key * 2);
+    }
+
+    for key in (0..1000u32).map(|key| key * 337 % 1000).filter(|key| key % 3 != 0) {
+        assert_eq!(arena_tree.remove(&key), box_tree.remove(&key));
+    }
+    assert_eq!(arena_tree.remove(&1), None);
+    assert_eq!(arena_tree.get(&1), None);
+    assert_eq!(arena_tree.get(&3), Some(&6));
+
+    assert_eq!(arena_tree.len(), box_tree.len());
+    assert_eq!(arena_tree.height(), box_tree.height());
+    assert!(arena_tree.iter().eq(box_tree.iter()));
+
+    /* the tree is still consistent after the moves of the nodes */
+    for key in 1000..1100 {
+        arena_tree.insert(key, key);
+        box_tree.insert(key, key);
+    }
+    assert!(arena_tree.iter().eq(box_tree.iter()));
+
+    for key in 0..1100 {
+        arena_tree.remove(&key);
+    }
+    assert!(arena_tree.is_empty());
+    assert_eq!(arena_tree.height(), 0);
+}
@@ -0,0 +1,114 @@
+use std::thread;
+
+use box_pointer::list::List;
+
+/* the tests that drop long lists run into a thread with a small stack:
+   a recursive drop would need one frame per node and overflow it */
+const SMALL_STACK: usize = 64 * 1024;
+const LONG_LIST: usize = 1_000_000;
+
+fn with_small_stack<F: FnOnce() + Send + 'static>(function: F) {
+
+    thread::Builder::new()
+        .stack_size(SMALL_STACK)
+        .spawn(function)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn push_and_pop_at_the_front() {
+
+    let mut list = List::new();
+    assert!(list.is_empty());
+    assert_eq!(list.pop(), None);
+
+    list.push(1);
+    list.push(2);
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.peek(), Some(&2));
+
+    assert_eq!(list.pop(), Some(2));
+    assert_eq!(list.pop(), Some(1));
+    assert_eq!(list.pop(), None);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn iterators_keep_the_order() {
+
+    let list: List<u8> = vec![1, 2, 3].into_iter().collect();
+    assert_eq!(list.len(), 3);
+
+    let borrowed: Vec<&u8> = list.iter().collect();
+    assert_eq!(borrowed, vec![&1, &2, &3]);
+
+    let mut total = 0;
+    for value in &list {
+        total += value;
+    }
+    assert_eq!(total, 6);
+
+    let moved: Vec<u8> = list.into_iter().collect();
+    assert_eq!(moved, vec![1, 2, 3]);
+}
+
+#[test]
+fn reverse_in_place() {
+
+    let mut list: List<&str> = ["a", "b", "c"].into_iter().collect();
+    list.reverse();
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec!["c", "b", "a"]);
+    assert_eq!(list.len(), 3);
+
+    let mut empty: List<u8> = List::new();
+    empty.reverse();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn display_the_chain_of_nodes() {
+
+    let list: List<u8> = (1..=3).collect();
+    assert_eq!(list.to_string(), "1 -> 2 -> 3 -> End");
+    assert_eq!(List::<u8>::new().to_string(), "End");
+}
+
+#[test]
+fn values_are_dropped() {
+
+    use std::rc::Rc;
+
+    let value = Rc::new(());
+    let list: List<Rc<()>> = (0..10).map(|_| value.clone()).collect();
+    assert_eq!(Rc::strong_count(&value), 11);
+
+    drop(list);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn dropping_a_long_list_does_not_overflow_the_stack() {
+
+    with_small_stack(|| {
+        let list: List<usize> = (0..LONG_LIST).collect();
+        assert_eq!(list.len(), LONG_LIST);
+        drop(list);
+    });
+}
+
+#[test]
+fn a_long_list_is_reversed_and_consumed_without_recursion() {
+
+    with_small_stack(|| {
+        let mut list: List<usize> = (0..LONG_LIST).collect();
+        list.reverse();
+        assert_eq!(list.peek(), Some(&(LONG_LIST - 1)));
+        assert_eq!(list.iter().count(), LONG_LIST);
+
+        /* the partially consumed list is dropped too */
+        let mut values = list.into_iter();
+        assert_eq!(values.next(), Some(LONG_LIST - 1));
+    });
+}
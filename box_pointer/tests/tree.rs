@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use box_pointer::tree::BinarySearchTree;
+
+/* pseudo-random keys without dependency (linear congruential generator) */
+fn random_keys(count: usize) -> Vec<u32> {
+
+    let mut state: u64 = 42;
+    (0..count)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as u32 % 1000
+        })
+        .collect()
+}
+
+/* the height of an AVL tree is at most 1.44 * log2(n + 2) */
+fn assert_balanced<V>(tree: &BinarySearchTree<u32, V>) {
+
+    let limit = 1.45 * ((tree.len() + 2) as f64).log2();
+    assert!(tree.height() as f64 <= limit, "height {} for {} nodes", tree.height(), tree.len());
+}
+
+fn keys<V>(tree: &BinarySearchTree<u32, V>) -> Vec<u32> {
+    tree.iter().map(|(key, _)| *key).collect()
+}
+
+#[test]
+fn insert_get_and_replace() {
+
+    let mut tree = BinarySearchTree::new();
+    assert!(tree.is_empty());
+    assert_eq!(tree.height(), 0);
+
+    assert_eq!(tree.insert(2, "two"), None);
+    assert_eq!(tree.insert(1, "one"), None);
+    assert_eq!(tree.insert(2, "deux"), Some("two"));
+
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.get(&2), Some(&"deux"));
+    assert!(tree.contains_key(&1));
+    assert!(!tree.contains_key(&3));
+}
+
+#[test]
+fn rotations_keep_sorted_inserts_balanced() {
+
+    let mut tree = BinarySearchTree::new();
+
+    /* 1, 2, 3 would be a chain: the root is rotated to the left */
+    for key in 1..=3 {
+        tree.insert(key, ());
+    }
+    let pre_order: Vec<u32> = tree.pre_order().map(|(key, _)| *key).collect();
+    assert_eq!(pre_order, vec![2, 1, 3]);
+
+    /* 3, 1, 2 needs a double rotation */
+    let tree: BinarySearchTree<u32, ()> = [3, 1, 2].into_iter().map(|key| (key, ())).collect();
+    let pre_order: Vec<u32> = tree.pre_order().map(|(key, _)| *key).collect();
+    assert_eq!(pre_order, vec![2, 1, 3]);
+
+    /* sorted inserts of 2^10 - 1 keys build a perfect tree */
+    let tree: BinarySearchTree<u32, ()> = (1..1024).map(|key| (key, ())).collect();
+    assert_eq!(tree.height(), 10);
+    assert_eq!(keys(&tree), (1..1024).collect::<Vec<_>>());
+}
+
+#[test]
+fn remove_leaves_nodes_with_one_or_two_children() {
+
+    let mut tree: BinarySearchTree<u32, u32> = (1..=7).map(|key| (key, key * 10)).collect();
+
+    /* 4 is the root, with two children */
+    assert_eq!(tree.remove(&4), Some(40));
+    assert_eq!(keys(&tree), vec![1, 2, 3, 5, 6, 7]);
+
+    /* a leaf */
+    assert_eq!(tree.remove(&7), Some(70));
+
+    /* 6 has one child left */
+    assert_eq!(tree.remove(&6), Some(60));
+    assert_eq!(tree.remove(&6), None);
+
+    assert_eq!(keys(&tree), vec![1, 2, 3, 5]);
+    assert_eq!(tree.len(), 4);
+    assert_balanced(&tree);
+}
+
+#[test]
+fn range_queries() {
+
+    let tree: BinarySearchTree<u32, ()> = (0..100).step_by(10).map(|key| (key, ())).collect();
+    let range = |start: Bound<u32>, end: Bound<u32>| -> Vec<u32> {
+        tree.range((start, end)).map(|(key, _)| *key).collect()
+    };
+
+    assert_eq!(range(Bound::Included(20), Bound::Excluded(50)), vec![20, 30, 40]);
+    assert_eq!(range(Bound::Excluded(20), Bound::Included(50)), vec![30, 40, 50]);
+    assert_eq!(range(Bound::Included(15), Bound::Included(35)), vec![20, 30]);
+    assert_eq!(range(Bound::Unbounded, Bound::Excluded(20)), vec![0, 10]);
+    assert_eq!(range(Bound::Included(85), Bound::Unbounded), vec![90]);
+    assert_eq!(range(Bound::Included(41), Bound::Excluded(49)), Vec::<u32>::new());
+
+    assert_eq!(tree.range(30..=30).count(), 1);
+    assert_eq!(tree.range(..).count(), 10);
+}
+
+#[test]
+fn behaves_as_a_btree_map() {
+
+    let mut tree = BinarySearchTree::new();
+    let mut expected = BTreeMap::new();
+
+    for (index, key) in random_keys(5000).into_iter().enumerate() {
+
+        /* one key out of three is removed instead of inserted */
+        if index % 3 == 0 {
+            assert_eq!(tree.remove(&key), expected.remove(&key));
+        } else {
+            assert_eq!(tree.insert(key, index), expected.insert(key, index));
+        }
+    }
+
+    assert_eq!(tree.len(), expected.len());
+    assert!(tree.iter().eq(expected.iter()));
+    assert!(tree.range(100..200).eq(expected.range(100..200)));
+    assert_balanced(&tree);
+}
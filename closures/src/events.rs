@@ -0,0 +1,156 @@
+/* an event emitter storing closures as listeners;
+   the registration method tells which closure trait is required:
+
+   - `on` accepts a `Fn` closure: it can be called any amount of times
+     and only reads what it captures,
+   - `on_mut` accepts a `FnMut` closure: it can modify what it captures,
+     so it is called through a mutable reference,
+   - `once` accepts a `FnOnce` closure: it can consume what it captures,
+     so it is called only once and then removed */
+
+use std::fmt;
+use std::mem;
+
+/// Handle returned by the registration of a listener, used to remove it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListenerId(u64);
+
+/* the closures are boxed, as every closure has its own type */
+enum Listener<E> {
+    Fn(Box<dyn Fn(&E)>),
+    FnMut(Box<dyn FnMut(&E)>),
+    Once(Box<dyn FnOnce(&E)>),
+}
+
+pub struct EventEmitter<E> {
+    listeners: Vec<(ListenerId, Listener<E>)>,
+    next_id: u64,
+}
+
+impl<E> EventEmitter<E> {
+
+    pub fn new() -> EventEmitter<E> {
+        EventEmitter {
+            listeners: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    fn register(
+        &mut self,
+        listener: Listener<E>,
+    ) -> ListenerId
+    {
+        let id = ListenerId(self.next_id);
+        self.next_id += 1;
+        self.listeners.push((id, listener));
+        id
+    }
+
+    /// Registers a listener called on every event;
+    /// a closure modifying what it captures is refused:
+    ///
+    /// ```compile_fail,E0525
+    /// let mut emitter = closures::events::EventEmitter::<u8>::new();
+    /// let mut count = 0;
+    /// emitter.on(move |_| count += 1);
+    /// ```
+    pub fn on<F: Fn(&E) + 'static>(
+        &mut self,
+        listener: F,
+    ) -> ListenerId
+    {
+        self.register(Listener::Fn(Box::new(listener)))
+    }
+
+    /// Registers a listener called on every event, that can modify its state;
+    /// a closure consuming what it captures is refused:
+    ///
+    /// ```compile_fail,E0525
+    /// let mut emitter = closures::events::EventEmitter::<u8>::new();
+    /// let text = String::from("text");
+    /// emitter.on_mut(move |_| drop(text));
+    /// ```
+    pub fn on_mut<F: FnMut(&E) + 'static>(
+        &mut self,
+        listener: F,
+    ) -> ListenerId
+    {
+        self.register(Listener::FnMut(Box::new(listener)))
+    }
+
+    /// Registers a listener called on the next event only,
+    /// that can consume what it captures
+    pub fn once<F: FnOnce(&E) + 'static>(
+        &mut self,
+        listener: F,
+    ) -> ListenerId
+    {
+        self.register(Listener::Once(Box::new(listener)))
+    }
+
+    /// Removes the listener, returns false if it has already been removed
+    pub fn off(
+        &mut self,
+        id: ListenerId,
+    ) -> bool
+    {
+        let count = self.listeners.len();
+        self.listeners.retain(|(listener_id, _)| *listener_id != id);
+        self.listeners.len() != count
+    }
+
+    /// Calls every listener in the registration order,
+    /// returns the amount of called listeners
+    pub fn emit(
+        &mut self,
+        event: &E,
+    ) -> usize
+    {
+        let listeners = mem::take(&mut self.listeners);
+        let called = listeners.len();
+
+        for (id, listener) in listeners {
+            match listener {
+                Listener::Fn(listener) => {
+                    listener(event);
+                    self.listeners.push((id, Listener::Fn(listener)));
+                }
+                Listener::FnMut(mut listener) => {
+                    listener(event);
+                    self.listeners.push((id, Listener::FnMut(listener)));
+                }
+
+                /* the closure is consumed by the call, it cannot be kept */
+                Listener::Once(listener) => listener(event),
+            }
+        }
+
+        called
+    }
+
+    pub fn listener_count(&self) -> usize {
+        self.listeners.len()
+    }
+}
+
+impl<E> Default for EventEmitter<E> {
+
+    fn default() -> EventEmitter<E> {
+        EventEmitter::new()
+    }
+}
+
+impl<E> fmt::Debug for EventEmitter<E> {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        formatter
+            .debug_struct("EventEmitter")
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
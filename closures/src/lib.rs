@@ -0,0 +1,155 @@
+pub mod events;
+pub mod memoization;
+pub mod pipeline;
+pub mod retry;
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use events::EventEmitter;
+use memoization::{
+    edit_distance,
+    fibonacci,
+    memoize,
+    Lazy,
+};
+use pipeline::{
+    short_circuit,
+    Pipeline,
+};
+use retry::{
+    retry,
+    Backoff,
+    RetryPolicy,
+};
+
+#[allow(unused)]
+pub fn run() {
+
+    /* the current context can be consumed by the closure,
+       "param" is borrowed as immutable (the closure implements "Fn" trait) */
+    let param = 10;
+    let closure = |p| {
+        p == param
+    };
+    println!("{}", param); // "param" is still usuable
+    println!("{}", closure(10));
+
+    /* the closure modifies one variable of the current context,
+       here "param" is borrowed as mutable,
+       the closture implements the "FnMut" trait;
+       "param" is not accessible anymore outside of the closure */
+    let mut param = 10;
+    let mut closure = |p| {
+        param += 5;
+        p == param
+    };
+    println!("{}", closure(10));
+
+    /* one solution to the problem just before is to wrap
+       the closure into a specific context */
+    let mut param = 10;
+    {
+        let mut closure = |p| {
+            param += 5;
+            p == param
+        };
+        println!("{}", closure(5));
+    }
+    println!("{}", param);
+
+    /* here "my_string" is moved, so it is not accessible
+       anymore outside of the closure;
+       the closure implements the trait "FnOnce",
+       note that without "move" keyword, the string
+       would have been copied (String implements Clone) */
+    let mut my_string = String::from("my string");
+    let mut closure = move |p| {
+        println!("{}", my_string);
+    };
+    // error: "my_string" has been moved: println!("{}", my_string);
+    closure(10);
+    // error: "my_string" has been moved: println!("{}", my_string);
+
+    /* closures can be stored to be called later, boxed,
+       for instance as the listeners of an event emitter;
+       a listener registered with "on" must implement "Fn" */
+    let mut emitter = EventEmitter::new();
+    let threshold = 10;
+    emitter.on(move |value: &u32| {
+        if *value > threshold {
+            println!("{} is too high", value);
+        }
+    });
+
+    /* a listener registered with "on_mut" implements "FnMut",
+       it keeps its own state between the calls */
+    let mut count = 0;
+    let counter = emitter.on_mut(move |_| {
+        count += 1;
+        println!("event {}", count);
+    });
+
+    /* a listener registered with "once" implements "FnOnce",
+       it can consume what it captures, so it is called only once */
+    let message = String::from("first event");
+    emitter.once(move |_| {
+        let consumed: String = message;
+        println!("{}", consumed);
+    });
+
+    emitter.emit(&5);
+    emitter.emit(&20);
+
+    /* the handle of a listener removes it */
+    emitter.off(counter);
+    println!("{}", emitter.emit(&30)); // 1
+
+    /* the closure returned by "memoize" caches the results of the given one;
+       its cache is modified through a RefCell, so it still implements "Fn" */
+    let calls = Cell::new(0);
+    let square = memoize(|value: u64| {
+        calls.set(calls.get() + 1);
+        value * value
+    });
+    println!("{}", square(12)); // 144
+    println!("{}", square(12)); // 144
+    println!("{}", calls.get()); // 1
+
+    /* a recursive function can be memoized too,
+       every sub-problem is computed only once */
+    println!("{}", fibonacci(90)); // 2880067194370816120
+    println!("{}", edit_distance("kitten", "sitting")); // 3
+
+    /* a lazy value calls its "FnOnce" initializer on its first access only */
+    let text = String::from("computed");
+    let lazy = Lazy::new(move || text + " once");
+    println!("{}", lazy.is_initialized()); // false
+    println!("{}", *lazy); // computed once
+    println!("{}", lazy.is_initialized()); // true
+
+    /* boxed closures can be chained as middlewares:
+       every one receives the request and the next step of the pipeline,
+       the configuration of a middleware is moved into its closure */
+    let prefix = String::from(">");
+    let pipeline = Pipeline::new(|name: String| format!("hello {}", name))
+        .with(move |name, next| format!("{} {}", prefix, next(name)))
+        .with(short_circuit(|name: &String| name.is_empty().then(|| String::from("nobody"))))
+        .with(|name: String, next| next(name.to_uppercase()));
+    println!("{}", pipeline.handle(String::from("world"))); // > hello WORLD
+    println!("{}", pipeline.handle(String::new())); // > nobody
+
+    /* an operation retried is called several times and usually changes
+       what it captures between the attempts: it is a "FnMut" closure */
+    let mut attempts = 0;
+    let policy = RetryPolicy::new(5, Backoff::Fixed(Duration::from_millis(1)));
+    let result = retry(&policy, || {
+        attempts += 1;
+        if attempts < 3 {
+            Err("not yet")
+        } else {
+            Ok(attempts)
+        }
+    });
+    println!("{:?}", result); // Ok(3)
+}
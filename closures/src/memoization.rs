@@ -0,0 +1,156 @@
+/* closures keeping a cache of their results;
+
+   a closure modifying what it captures only implements FnMut,
+   so a cache directly captured would make the memoized function FnMut too;
+   the cache is wrapped into a RefCell instead, that can be modified
+   through a shared reference: the closure only reads what it captures
+   (the RefCell), so it still implements Fn */
+
+use std::cell::{
+    Cell,
+    OnceCell,
+    RefCell,
+};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Deref;
+
+/// Returns the function with a cache:
+/// it is called once per key, the next calls return the cached value
+pub fn memoize<K, V, F>(function: F) -> impl Fn(K) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(K) -> V,
+{
+    /* the cache is moved into the closure, it lives as long as the closure */
+    let cache: RefCell<HashMap<K, V>> = RefCell::new(HashMap::new());
+
+    move |key: K| {
+
+        if let Some(value) = cache.borrow().get(&key) {
+            return value.clone();
+        }
+
+        let value = function(key.clone());
+        cache.borrow_mut().insert(key, value.clone());
+        value
+    }
+}
+
+fn call_recursive<K, V, F>(
+    function: &F,
+    cache: &RefCell<HashMap<K, V>>,
+    key: K,
+) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&dyn Fn(K) -> V, K) -> V,
+{
+    if let Some(value) = cache.borrow().get(&key) {
+        return value.clone();
+    }
+
+    /* the cache is not borrowed during the call,
+       as the recursive calls modify it */
+    let recurse = |key: K| call_recursive(function, cache, key);
+    let value = function(&recurse, key.clone());
+
+    cache.borrow_mut().insert(key, value.clone());
+    value
+}
+
+/// Returns the recursive function with a cache;
+/// a closure cannot call itself, so the function receives
+/// the memoized function as first parameter to make its recursive calls
+pub fn memoize_recursive<K, V, F>(function: F) -> impl Fn(K) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&dyn Fn(K) -> V, K) -> V,
+{
+    let cache = RefCell::new(HashMap::new());
+    move |key: K| call_recursive(&function, &cache, key)
+}
+
+/// Returns the n-th Fibonacci number, every number computed once
+pub fn fibonacci(n: u64) -> u64 {
+
+    let fibonacci = memoize_recursive(|fibonacci: &dyn Fn(u64) -> u64, n: u64| {
+        if n < 2 {
+            n
+        } else {
+            fibonacci(n - 1) + fibonacci(n - 2)
+        }
+    });
+
+    fibonacci(n)
+}
+
+/// Returns the minimum amount of insertions, deletions and substitutions
+/// of characters to change the first text into the second one
+pub fn edit_distance(
+    first: &str,
+    second: &str,
+) -> usize
+{
+    let first: Vec<char> = first.chars().collect();
+    let second: Vec<char> = second.chars().collect();
+
+    /* the key is the length of the prefixes of both texts,
+       the closure borrows the characters, it does not need to own them */
+    let distance = memoize_recursive(|distance: &dyn Fn((usize, usize)) -> usize, (i, j)| {
+        if i == 0 || j == 0 {
+            return i + j;
+        }
+
+        let substitution = distance((i - 1, j - 1)) + usize::from(first[i - 1] != second[j - 1]);
+        let deletion = distance((i - 1, j)) + 1;
+        let insertion = distance((i, j - 1)) + 1;
+        substitution.min(deletion).min(insertion)
+    });
+
+    distance((first.len(), second.len()))
+}
+
+/// A value computed on its first access only, by the given closure;
+/// the closure implements FnOnce, it can consume what it captures
+pub struct Lazy<T, F: FnOnce() -> T> {
+    value: OnceCell<T>,
+
+    /* the closure is taken out of the cell when it is called */
+    initializer: Cell<Option<F>>,
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+
+    pub fn new(initializer: F) -> Lazy<T, F> {
+        Lazy {
+            value: OnceCell::new(),
+            initializer: Cell::new(Some(initializer)),
+        }
+    }
+
+    /// Returns the value, computes it first if needed
+    pub fn get(&self) -> &T {
+
+        self.value.get_or_init(|| match self.initializer.take() {
+            Some(initializer) => initializer(),
+            None => panic!("the initializer of the lazy value has panicked"),
+        })
+    }
+
+    /// Returns true if the value has been computed
+    pub fn is_initialized(&self) -> bool {
+        self.value.get().is_some()
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
@@ -0,0 +1,176 @@
+/* a pipeline of middlewares, like the ones of an HTTP server:
+   every middleware is a closure receiving the request
+   and the next step of the pipeline, it can change the request,
+   change the response, call the next step several times or not at all;
+
+   the middlewares are built by functions returning `move` closures:
+   the configuration given to the function is moved into the closure,
+   that keeps it as long as the pipeline lives */
+
+use std::fmt::Debug;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// Next step of the pipeline, given to a middleware
+pub type Next<'a, Req, Resp> = &'a dyn Fn(Req) -> Resp;
+
+pub type Middleware<Req, Resp> = Box<dyn Fn(Req, Next<Req, Resp>) -> Resp>;
+
+pub struct Pipeline<Req, Resp> {
+
+    /* the first middleware receives the request first */
+    middlewares: Vec<Middleware<Req, Resp>>,
+
+    handler: Box<dyn Fn(Req) -> Resp>,
+}
+
+impl<Req, Resp> Pipeline<Req, Resp> {
+
+    /// Creates a pipeline ending with the given handler
+    pub fn new<H: Fn(Req) -> Resp + 'static>(handler: H) -> Pipeline<Req, Resp> {
+        Pipeline {
+            middlewares: Vec::new(),
+            handler: Box::new(handler),
+        }
+    }
+
+    /// Adds a middleware, after the ones already added
+    pub fn with<M: Fn(Req, Next<Req, Resp>) -> Resp + 'static>(
+        mut self,
+        middleware: M,
+    ) -> Pipeline<Req, Resp>
+    {
+        self.middlewares.push(Box::new(middleware));
+        self
+    }
+
+    /* the next step of a middleware is a closure calling the following one */
+    fn call(
+        &self,
+        index: usize,
+        request: Req,
+    ) -> Resp
+    {
+        match self.middlewares.get(index) {
+            Some(middleware) => middleware(request, &|request| self.call(index + 1, request)),
+            None => (self.handler)(request),
+        }
+    }
+
+    /// Sends the request through every middleware, then to the handler
+    pub fn handle(
+        &self,
+        request: Req,
+    ) -> Resp
+    {
+        self.call(0, request)
+    }
+
+    pub fn len(&self) -> usize {
+        self.middlewares.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.middlewares.is_empty()
+    }
+}
+
+impl<Req: 'static, Resp: 'static> Pipeline<Req, Resp> {
+
+    /// Returns a pipeline converting the responses of this one,
+    /// the whole pipeline is moved into the handler of the new one
+    pub fn and_then<Other, F: Fn(Resp) -> Other + 'static>(
+        self,
+        convert: F,
+    ) -> Pipeline<Req, Other>
+    {
+        Pipeline::new(move |request| convert(self.handle(request)))
+    }
+}
+
+/// Returns one middleware running the first one, then the second one
+pub fn compose<Req, Resp, First, Second>(
+    first: First,
+    second: Second,
+) -> impl Fn(Req, Next<Req, Resp>) -> Resp
+where
+    First: Fn(Req, Next<Req, Resp>) -> Resp,
+    Second: Fn(Req, Next<Req, Resp>) -> Resp,
+{
+    /* the next step of the first middleware is the second one */
+    move |request, next| first(request, &|request| second(request, next))
+}
+
+/// Returns a middleware giving a description of every request
+/// and of its response to the log function
+pub fn logging<Req, Resp, L>(log: L) -> impl Fn(Req, Next<Req, Resp>) -> Resp
+where
+    Req: Debug,
+    Resp: Debug,
+    L: Fn(String),
+{
+    move |request, next| {
+        log(format!("request: {:?}", request));
+        let response = next(request);
+        log(format!("response: {:?}", response));
+        response
+    }
+}
+
+/// Returns a middleware giving the duration of the rest of the pipeline
+/// to the report function
+pub fn timing<Req, Resp, R>(report: R) -> impl Fn(Req, Next<Req, Resp>) -> Resp
+where
+    R: Fn(Duration),
+{
+    move |request, next| {
+        let start = Instant::now();
+        let response = next(request);
+        report(start.elapsed());
+        response
+    }
+}
+
+/// Returns a middleware answering directly when the check returns a response,
+/// the rest of the pipeline is not called
+pub fn short_circuit<Req, Resp, C>(check: C) -> impl Fn(Req, Next<Req, Resp>) -> Resp
+where
+    C: Fn(&Req) -> Option<Resp>,
+{
+    move |request, next| match check(&request) {
+        Some(response) => response,
+        None => next(request),
+    }
+}
+
+/// Returns a middleware calling the rest of the pipeline again
+/// while the response has to be retried, at most the given amount of attempts;
+/// the request is cloned for every attempt
+///
+/// Panics if there is no attempt: the middleware must return a response,
+/// so the rest of the pipeline is called at least once
+pub fn retry<Req, Resp, P>(
+    attempts: usize,
+    should_retry: P,
+) -> impl Fn(Req, Next<Req, Resp>) -> Resp
+where
+    Req: Clone,
+    P: Fn(&Resp) -> bool,
+{
+    assert!(attempts >= 1, "at least one attempt is required");
+
+    move |request, next| {
+        let mut response = next(request.clone());
+
+        for _ in 1..attempts {
+            if !should_retry(&response) {
+                break;
+            }
+            response = next(request.clone());
+        }
+
+        response
+    }
+}
@@ -0,0 +1,262 @@
+/* calls an operation again while it fails, waiting longer and longer
+   between the attempts;
+
+   the operation is a FnMut closure:
+   - not FnOnce, as it is called several times,
+   - not only Fn, as an operation usually changes what it captures
+     between two attempts (a counter, a connection to open again...) */
+
+use std::collections::hash_map::RandomState;
+use std::hash::{
+    BuildHasher,
+    Hasher,
+};
+use std::thread;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// Delay between two attempts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Backoff {
+
+    /// Always the same delay
+    Fixed(Duration),
+
+    /// The delay grows by the increment after every attempt, up to the max
+    Linear {
+        initial: Duration,
+        increment: Duration,
+        max: Duration,
+    },
+
+    /// The delay doubles after every attempt, up to the max;
+    /// the jitter (from 0 to 1) is the part of the delay that is random,
+    /// so the clients failing together do not retry all together
+    Exponential {
+        initial: Duration,
+        max: Duration,
+        jitter: f64,
+    },
+}
+
+impl Backoff {
+
+    /// Returns the delay before the given retry (1 for the first one), without jitter
+    pub fn base_delay(
+        &self,
+        retry: u32,
+    ) -> Duration
+    {
+        let previous_retries = retry.saturating_sub(1);
+
+        match *self {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Linear { initial, increment, max } => increment
+                .checked_mul(previous_retries)
+                .and_then(|growth| initial.checked_add(growth))
+                .unwrap_or(max)
+                .min(max),
+            Backoff::Exponential { initial, max, .. } => {
+                let factor = 2u32.checked_pow(previous_retries).unwrap_or(u32::MAX);
+                initial.checked_mul(factor).unwrap_or(max).min(max)
+            }
+        }
+    }
+}
+
+/// Source of time of the retries, the tests give one that does not wait
+pub trait Clock {
+
+    /// Time elapsed since any fixed instant
+    fn now(&self) -> Duration;
+
+    fn sleep(
+        &mut self,
+        duration: Duration,
+    );
+}
+
+/// The real time, sleeping blocks the current thread
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+
+    pub fn new() -> SystemClock {
+        SystemClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn sleep(
+        &mut self,
+        duration: Duration,
+    ) {
+        thread::sleep(duration)
+    }
+}
+
+pub struct RetryPolicy<E> {
+    max_attempts: u32,
+    backoff: Backoff,
+    timeout: Option<Duration>,
+
+    /* stored boxed, so the policy type does not depend on the closure type */
+    retryable: Box<dyn Fn(&E) -> bool>,
+
+    seed: u64,
+}
+
+impl<E> RetryPolicy<E> {
+
+    /// Every error is retried, the operation is called at least once
+    pub fn new(
+        max_attempts: u32,
+        backoff: Backoff,
+    ) -> RetryPolicy<E>
+    {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            backoff,
+            timeout: None,
+            retryable: Box::new(|_| true),
+
+            /* a random seed per process, so the jitter differs between processes */
+            seed: RandomState::new().build_hasher().finish(),
+        }
+    }
+
+    /// Only the errors accepted by the predicate are retried
+    pub fn retry_if<P: Fn(&E) -> bool + 'static>(
+        mut self,
+        retryable: P,
+    ) -> RetryPolicy<E>
+    {
+        self.retryable = Box::new(retryable);
+        self
+    }
+
+    /// No retry starts if it would end after the timeout,
+    /// counted from the first attempt
+    pub fn timeout(
+        mut self,
+        timeout: Duration,
+    ) -> RetryPolicy<E>
+    {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Makes the jitter reproducible
+    pub fn seed(
+        mut self,
+        seed: u64,
+    ) -> RetryPolicy<E>
+    {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the delay before the given retry (1 for the first one), jitter included
+    pub fn delay(
+        &self,
+        retry: u32,
+    ) -> Duration
+    {
+        let delay = self.backoff.base_delay(retry);
+
+        match self.backoff {
+            Backoff::Exponential { jitter, .. } if jitter > 0.0 => {
+                let random = random_fraction(self.seed ^ retry as u64);
+                delay.mul_f64(1.0 - jitter.min(1.0) * random)
+            }
+            _ => delay,
+        }
+    }
+}
+
+/// Returns a number from 0 (included) to 1 (excluded) derived from the seed
+/// (splitmix64 mixing function)
+fn random_fraction(seed: u64) -> f64 {
+
+    let mut value = seed.wrapping_add(0x9E3779B97F4A7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D049BB133111EB);
+    value ^= value >> 31;
+
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Calls the operation until it succeeds, waiting with the given clock
+/// between the attempts; returns the last error when the attempts are over,
+/// when the error is not retryable or when the timeout would be exceeded
+pub fn retry_with_clock<T, E, C, F>(
+    policy: &RetryPolicy<E>,
+    clock: &mut C,
+    mut operation: F,
+) -> Result<T, E>
+where
+    C: Clock,
+    F: FnMut() -> Result<T, E>,
+{
+    let start = clock.now();
+    let mut attempt = 1;
+
+    loop {
+        let error = match operation() {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+
+        if attempt >= policy.max_attempts || !(policy.retryable)(&error) {
+            return Err(error);
+        }
+
+        let delay = policy.delay(attempt);
+        if let Some(timeout) = policy.timeout {
+            if clock.now().saturating_sub(start).saturating_add(delay) > timeout {
+                return Err(error);
+            }
+        }
+
+        clock.sleep(delay);
+        attempt += 1;
+    }
+}
+
+/// Calls the operation until it succeeds, the thread sleeps between the attempts;
+/// a closure consuming what it captures cannot be retried:
+///
+/// ```compile_fail,E0525
+/// use closures::retry::{retry, Backoff, RetryPolicy};
+///
+/// let policy = RetryPolicy::new(3, Backoff::Fixed(std::time::Duration::ZERO));
+/// let message = String::from("sent once");
+/// let result: Result<(), String> = retry(&policy, move || Err(message));
+/// ```
+pub fn retry<T, E, F>(
+    policy: &RetryPolicy<E>,
+    operation: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    retry_with_clock(policy, &mut SystemClock::new(), operation)
+}
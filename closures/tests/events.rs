@@ -0,0 +1,104 @@
+use std::cell::{
+    Cell,
+    RefCell,
+};
+use std::rc::Rc;
+
+use closures::events::EventEmitter;
+
+/* the listeners must be 'static, so the tests observe them
+   through shared counters (Rc) they can modify through a shared reference (Cell) */
+
+#[test]
+fn fn_listeners_only_read_their_captures() {
+
+    let mut emitter = EventEmitter::new();
+    let calls = Rc::new(Cell::new(0));
+
+    /* the closure only reads "calls" (the Cell is modified through &),
+       so it implements Fn */
+    let captured = calls.clone();
+    emitter.on(move |value: &u32| captured.set(captured.get() + value));
+
+    emitter.emit(&1);
+    emitter.emit(&2);
+    assert_eq!(calls.get(), 3);
+    assert_eq!(emitter.listener_count(), 1);
+}
+
+#[test]
+fn fn_mut_listeners_keep_their_state() {
+
+    let mut emitter = EventEmitter::new();
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    /* "count" is modified by the closure: it only implements FnMut */
+    let captured = seen.clone();
+    let mut count = 0;
+    emitter.on_mut(move |value: &char| {
+        count += 1;
+        captured.borrow_mut().push((count, *value));
+    });
+
+    emitter.emit(&'a');
+    emitter.emit(&'b');
+    assert_eq!(*seen.borrow(), vec![(1, 'a'), (2, 'b')]);
+}
+
+#[test]
+fn once_listeners_consume_their_captures() {
+
+    let mut emitter = EventEmitter::new();
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    /* "message" is moved out of the closure when it is called:
+       it only implements FnOnce */
+    let captured = received.clone();
+    let message = String::from("hello");
+    emitter.once(move |_: &()| captured.borrow_mut().push(message));
+
+    assert_eq!(emitter.emit(&()), 1);
+    assert_eq!(emitter.emit(&()), 0);
+    assert_eq!(*received.borrow(), vec!["hello".to_string()]);
+    assert_eq!(emitter.listener_count(), 0);
+}
+
+#[test]
+fn listeners_are_called_in_registration_order() {
+
+    let mut emitter = EventEmitter::new();
+    let order = Rc::new(RefCell::new(Vec::new()));
+
+    let first = order.clone();
+    emitter.on(move |_: &u8| first.borrow_mut().push("on"));
+    let second = order.clone();
+    emitter.once(move |_| second.borrow_mut().push("once"));
+    let third = order.clone();
+    emitter.on_mut(move |_| third.borrow_mut().push("on_mut"));
+
+    emitter.emit(&0);
+    emitter.emit(&0);
+    assert_eq!(*order.borrow(), vec!["on", "once", "on_mut", "on", "on_mut"]);
+}
+
+#[test]
+fn handles_unsubscribe_their_listener() {
+
+    let mut emitter = EventEmitter::new();
+    let calls = Rc::new(Cell::new(0));
+
+    let captured = calls.clone();
+    let kept = emitter.on(move |_: &u8| captured.set(captured.get() + 1));
+    let captured = calls.clone();
+    let removed = emitter.on(move |_| captured.set(captured.get() + 100));
+    let captured = calls.clone();
+    let never_called = emitter.once(move |_| captured.set(captured.get() + 1000));
+
+    assert!(emitter.off(removed));
+    assert!(emitter.off(never_called));
+    assert!(!emitter.off(removed));
+    assert_ne!(kept, removed);
+
+    assert_eq!(emitter.emit(&0), 1);
+    assert_eq!(calls.get(), 1);
+}
@@ -0,0 +1,136 @@
+use std::cell::Cell;
+
+use closures::memoization::{
+    edit_distance,
+    fibonacci,
+    memoize,
+    memoize_recursive,
+    Lazy,
+};
+
+/* the edit distance without cache, to compare with */
+fn naive_edit_distance(
+    first: &[u8],
+    second: &[u8],
+) -> usize
+{
+    match (first.split_last(), second.split_last()) {
+        (None, _) => second.len(),
+        (_, None) => first.len(),
+        (Some((first_last, first_rest)), Some((second_last, second_rest))) => {
+            let substitution = naive_edit_distance(first_rest, second_rest)
+                + usize::from(first_last != second_last);
+            let deletion = naive_edit_distance(first_rest, second) + 1;
+            let insertion = naive_edit_distance(first, second_rest) + 1;
+            substitution.min(deletion).min(insertion)
+        }
+    }
+}
+
+#[test]
+fn memoized_functions_are_called_once_per_key() {
+
+    let calls = Cell::new(0);
+    let length = memoize(|text: String| {
+        calls.set(calls.get() + 1);
+        text.len()
+    });
+
+    assert_eq!(length("one".to_string()), 3);
+    assert_eq!(length("three".to_string()), 5);
+    assert_eq!(length("one".to_string()), 3);
+    assert_eq!(calls.get(), 2);
+}
+
+#[test]
+fn memoized_functions_implement_fn() {
+
+    /* the cache is into a RefCell, so the memoized closure can be
+       called through a shared reference, by several callers */
+    fn call_twice(function: &impl Fn(u32) -> u32) -> u32 {
+        function(2) + function(2)
+    }
+
+    let double = memoize(|value: u32| value * 2);
+    assert_eq!(call_twice(&double), 8);
+}
+
+#[test]
+fn recursive_calls_use_the_cache() {
+
+    let calls = Cell::new(0);
+    let fibonacci = memoize_recursive(|fibonacci: &dyn Fn(u32) -> u64, n: u32| {
+        calls.set(calls.get() + 1);
+        if n < 2 {
+            n as u64
+        } else {
+            fibonacci(n - 1) + fibonacci(n - 2)
+        }
+    });
+
+    assert_eq!(fibonacci(50), 12586269025);
+
+    /* every number from 0 to 50 is computed once */
+    assert_eq!(calls.get(), 51);
+}
+
+#[test]
+fn fibonacci_numbers() {
+
+    let expected = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34];
+    for (n, value) in expected.iter().enumerate() {
+        assert_eq!(fibonacci(n as u64), *value);
+    }
+    assert_eq!(fibonacci(93), 12200160415121876738);
+}
+
+#[test]
+fn edit_distance_matches_the_naive_recursion() {
+
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("", "abc"), 3);
+    assert_eq!(edit_distance("same", "same"), 0);
+    assert_eq!(edit_distance("é", "e"), 1);
+
+    let words = ["", "a", "ab", "flaw", "lawn", "intention", "execution"];
+    for first in words {
+        for second in words {
+            assert_eq!(
+                edit_distance(first, second),
+                naive_edit_distance(first.as_bytes(), second.as_bytes()),
+            );
+        }
+    }
+
+    /* far too long for the naive recursion */
+    let long = "ab".repeat(200);
+    assert_eq!(edit_distance(&long, &long[1..]), 1);
+}
+
+#[test]
+fn lazy_values_are_computed_once_on_first_access() {
+
+    let calls = Cell::new(0);
+    let lazy = Lazy::new(|| {
+        calls.set(calls.get() + 1);
+        42
+    });
+
+    assert!(!lazy.is_initialized());
+    assert_eq!(calls.get(), 0);
+
+    assert_eq!(*lazy.get(), 42);
+    assert_eq!(*lazy, 42);
+    assert!(lazy.is_initialized());
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn lazy_initializers_can_consume_their_captures() {
+
+    let words = vec!["lazy", "value"];
+
+    /* "words" is moved and consumed: the initializer only implements FnOnce */
+    let lazy = Lazy::new(move || words.into_iter().collect::<Vec<_>>().join(" "));
+    assert_eq!(lazy.as_str(), "lazy value");
+}
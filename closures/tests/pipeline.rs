@@ -0,0 +1,222 @@
+use std::cell::{
+    Cell,
+    RefCell,
+};
+use std::rc::Rc;
+use std::time::Duration;
+
+use closures::pipeline::{
+    compose,
+    logging,
+    retry,
+    short_circuit,
+    timing,
+    Next,
+    Pipeline,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Request {
+    path: String,
+    user: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+fn request(
+    path: &str,
+    user: Option<&str>,
+) -> Request
+{
+    Request {
+        path: path.to_string(),
+        user: user.map(String::from),
+    }
+}
+
+fn respond(
+    status: u16,
+    body: &str,
+) -> Response
+{
+    Response {
+        status,
+        body: body.to_string(),
+    }
+}
+
+/* answers with the path and the user */
+fn application(request: Request) -> Response {
+    respond(200, &format!("{} for {}", request.path, request.user.unwrap_or_default()))
+}
+
+/* refuses the requests without user */
+fn authentication(request: &Request) -> Option<Response> {
+    request.user.is_none().then(|| respond(401, "unauthorized"))
+}
+
+#[test]
+fn a_pipeline_without_middleware_calls_the_handler() {
+
+    let pipeline = Pipeline::new(application);
+    assert!(pipeline.is_empty());
+    assert_eq!(pipeline.handle(request("/", Some("jean"))), respond(200, "/ for jean"));
+}
+
+#[test]
+fn middlewares_are_called_in_order() {
+
+    let order = Rc::new(RefCell::new(Vec::new()));
+
+    /* every middleware records its name before and after the next steps */
+    let step = |name: &'static str| {
+        let order = order.clone();
+        move |request: Request, next: Next<Request, Response>| {
+            order.borrow_mut().push(format!("{} in", name));
+            let response = next(request);
+            order.borrow_mut().push(format!("{} out", name));
+            response
+        }
+    };
+
+    let pipeline = Pipeline::new(application)
+        .with(step("first"))
+        .with(step("second"));
+    assert_eq!(pipeline.len(), 2);
+
+    pipeline.handle(request("/", Some("jean")));
+    assert_eq!(*order.borrow(), vec!["first in", "second in", "second out", "first out"]);
+}
+
+#[test]
+fn middlewares_can_change_the_request_and_the_response() {
+
+    /* the configuration is moved into the closure */
+    let prefix = String::from("/api");
+    let pipeline = Pipeline::new(application)
+        .with(|request: Request, next: Next<Request, Response>| {
+            let mut response = next(request);
+            response.body = response.body.to_uppercase();
+            response
+        })
+        .with(move |mut request: Request, next: Next<Request, Response>| {
+            request.path = format!("{}{}", prefix, request.path);
+            next(request)
+        });
+
+    assert_eq!(
+        pipeline.handle(request("/users", Some("jean"))),
+        respond(200, "/API/USERS FOR JEAN"),
+    );
+}
+
+#[test]
+fn short_circuits_skip_the_rest_of_the_pipeline() {
+
+    let calls = Rc::new(Cell::new(0));
+    let counted = calls.clone();
+
+    let pipeline = Pipeline::new(move |request| {
+        counted.set(counted.get() + 1);
+        application(request)
+    })
+    .with(short_circuit(authentication));
+
+    assert_eq!(pipeline.handle(request("/", None)), respond(401, "unauthorized"));
+    assert_eq!(calls.get(), 0);
+
+    assert_eq!(pipeline.handle(request("/", Some("jean"))).status, 200);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn retries_call_the_rest_of_the_pipeline_again() {
+
+    /* the handler fails twice before answering */
+    let failures = Rc::new(Cell::new(2));
+    let remaining = failures.clone();
+    let flaky = move |request: Request| {
+        if remaining.get() > 0 {
+            remaining.set(remaining.get() - 1);
+            return respond(503, "unavailable");
+        }
+        application(request)
+    };
+
+    let pipeline = Pipeline::new(flaky).with(retry(3, |response: &Response| response.status == 503));
+    assert_eq!(pipeline.handle(request("/", Some("jean"))).status, 200);
+
+    /* not enough attempts: the last response is returned */
+    failures.set(5);
+    assert_eq!(pipeline.handle(request("/", Some("jean"))).status, 503);
+    assert_eq!(failures.get(), 2);
+}
+
+#[test]
+#[should_panic(expected = "at least one attempt is required")]
+fn retries_need_one_attempt() {
+    let _ = retry::<Request, Response, _>(0, |response: &Response| response.status == 503);
+}
+
+#[test]
+fn one_attempt_calls_the_rest_of_the_pipeline_once() {
+
+    let calls = Rc::new(Cell::new(0));
+    let counted = calls.clone();
+    let unavailable = move |_: Request| {
+        counted.set(counted.get() + 1);
+        respond(503, "unavailable")
+    };
+
+    let pipeline = Pipeline::new(unavailable).with(retry(1, |response: &Response| response.status == 503));
+    assert_eq!(pipeline.handle(request("/", None)).status, 503);
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn logging_and_timing_report_through_their_closures() {
+
+    let lines = Rc::new(RefCell::new(Vec::new()));
+    let durations = Rc::new(RefCell::new(Vec::<Duration>::new()));
+
+    let logged = lines.clone();
+    let timed = durations.clone();
+    let pipeline = Pipeline::new(|_: u8| 'a')
+        .with(logging(move |line| logged.borrow_mut().push(line)))
+        .with(timing(move |duration| timed.borrow_mut().push(duration)));
+
+    pipeline.handle(7);
+    assert_eq!(*lines.borrow(), vec!["request: 7", "response: 'a'"]);
+    assert_eq!(durations.borrow().len(), 1);
+}
+
+#[test]
+fn composed_middlewares_behave_as_added_one_by_one() {
+
+    let add = |value: i32| move |request: i32, next: Next<i32, i32>| next(request + value);
+    let double = |request: i32, next: Next<i32, i32>| next(request) * 2;
+
+    let composed = Pipeline::new(|request: i32| request).with(compose(add(1), compose(double, add(10))));
+    let sequential = Pipeline::new(|request: i32| request)
+        .with(add(1))
+        .with(double)
+        .with(add(10));
+
+    assert_eq!(composed.handle(5), 32);
+    assert_eq!(sequential.handle(5), 32);
+}
+
+#[test]
+fn and_then_converts_the_responses() {
+
+    let pipeline = Pipeline::new(application)
+        .with(short_circuit(authentication))
+        .and_then(|response: Response| response.status);
+
+    assert_eq!(pipeline.handle(request("/", None)), 401);
+    assert_eq!(pipeline.handle(request("/", Some("jean"))), 200);
+}
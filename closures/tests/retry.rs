@@ -0,0 +1,224 @@
+use std::time::Duration;
+
+use closures::retry::{
+    retry_with_clock,
+    Backoff,
+    Clock,
+    RetryPolicy,
+};
+
+/* a clock that does not wait, it records the sleeps */
+#[derive(Default)]
+struct FakeClock {
+    now: Duration,
+    sleeps: Vec<Duration>,
+}
+
+impl Clock for FakeClock {
+
+    fn now(&self) -> Duration {
+        self.now
+    }
+
+    fn sleep(
+        &mut self,
+        duration: Duration,
+    ) {
+        self.now = self.now.saturating_add(duration);
+        self.sleeps.push(duration);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Unavailable,
+    NotFound,
+}
+
+fn millis(milliseconds: u64) -> Duration {
+    Duration::from_millis(milliseconds)
+}
+
+/* an operation failing the given amount of times, then succeeding;
+   it changes its counter, so it is a FnMut closure */
+fn failing(mut failures: u32) -> impl FnMut() -> Result<&'static str, Error> {
+    move || {
+        if failures == 0 {
+            return Ok("done");
+        }
+        failures -= 1;
+        Err(Error::Unavailable)
+    }
+}
+
+#[test]
+fn a_success_is_not_retried() {
+
+    let mut clock = FakeClock::default();
+    let policy = RetryPolicy::new(3, Backoff::Fixed(millis(10)));
+
+    assert_eq!(retry_with_clock(&policy, &mut clock, failing(0)), Ok("done"));
+    assert!(clock.sleeps.is_empty());
+}
+
+#[test]
+fn fixed_backoff_waits_the_same_delay() {
+
+    let mut clock = FakeClock::default();
+    let policy = RetryPolicy::new(5, Backoff::Fixed(millis(10)));
+
+    assert_eq!(retry_with_clock(&policy, &mut clock, failing(3)), Ok("done"));
+    assert_eq!(clock.sleeps, vec![millis(10); 3]);
+}
+
+#[test]
+fn linear_backoff_grows_by_the_increment() {
+
+    let mut clock = FakeClock::default();
+    let backoff = Backoff::Linear {
+        initial: millis(100),
+        increment: millis(50),
+        max: millis(250),
+    };
+    let policy = RetryPolicy::new(10, backoff);
+
+    assert_eq!(retry_with_clock(&policy, &mut clock, failing(5)), Ok("done"));
+    assert_eq!(
+        clock.sleeps,
+        vec![millis(100), millis(150), millis(200), millis(250), millis(250)],
+    );
+}
+
+#[test]
+fn huge_delays_stop_at_the_max() {
+
+    let backoff = Backoff::Linear {
+        initial: millis(100),
+        increment: Duration::MAX,
+        max: Duration::MAX,
+    };
+    assert_eq!(backoff.base_delay(1), millis(100));
+    assert_eq!(backoff.base_delay(2), Duration::MAX);
+    assert_eq!(backoff.base_delay(u32::MAX), Duration::MAX);
+
+    let backoff = Backoff::Linear {
+        initial: millis(100),
+        increment: Duration::from_secs(u64::MAX / 2),
+        max: Duration::from_secs(3600),
+    };
+    assert_eq!(backoff.base_delay(u32::MAX), Duration::from_secs(3600));
+
+    let backoff = Backoff::Exponential {
+        initial: Duration::MAX,
+        max: Duration::MAX,
+        jitter: 0.0,
+    };
+    assert_eq!(backoff.base_delay(u32::MAX), Duration::MAX);
+}
+
+#[test]
+fn a_huge_delay_does_not_overflow_the_timeout() {
+
+    let mut clock = FakeClock::default();
+    let backoff = Backoff::Linear {
+        initial: millis(10),
+        increment: Duration::MAX,
+        max: Duration::MAX,
+    };
+    let policy = RetryPolicy::new(u32::MAX, backoff).timeout(millis(1000));
+
+    /* the second retry would end after the timeout, far after it */
+    assert_eq!(retry_with_clock(&policy, &mut clock, failing(10)), Err(Error::Unavailable));
+    assert_eq!(clock.sleeps, vec![millis(10)]);
+}
+
+#[test]
+fn exponential_backoff_doubles_up_to_the_max() {
+
+    let mut clock = FakeClock::default();
+    let backoff = Backoff::Exponential {
+        initial: millis(100),
+        max: millis(1000),
+        jitter: 0.0,
+    };
+    let policy = RetryPolicy::new(10, backoff);
+
+    assert_eq!(retry_with_clock(&policy, &mut clock, failing(6)), Ok("done"));
+    assert_eq!(
+        clock.sleeps,
+        vec![millis(100), millis(200), millis(400), millis(800), millis(1000), millis(1000)],
+    );
+
+    /* no overflow after many retries */
+    assert_eq!(backoff.base_delay(200), millis(1000));
+}
+
+#[test]
+fn jitter_stays_within_its_part_of_the_delay() {
+
+    let backoff = Backoff::Exponential {
+        initial: millis(100),
+        max: millis(10_000),
+        jitter: 0.5,
+    };
+    let policy: RetryPolicy<Error> = RetryPolicy::new(10, backoff).seed(7);
+
+    for retry in 1..10 {
+        let base = backoff.base_delay(retry);
+        let delay = policy.delay(retry);
+        assert!(delay <= base && delay >= base / 2, "{:?} for {:?}", delay, base);
+
+        /* the same seed gives the same delays */
+        assert_eq!(delay, policy.delay(retry));
+    }
+
+    let other: RetryPolicy<Error> = RetryPolicy::new(10, backoff).seed(8);
+    assert!((1..10).any(|retry| other.delay(retry) != policy.delay(retry)));
+}
+
+#[test]
+fn attempts_are_limited() {
+
+    let mut clock = FakeClock::default();
+    let policy = RetryPolicy::new(3, Backoff::Fixed(millis(10)));
+
+    let mut attempts = 0;
+    let result: Result<(), Error> = retry_with_clock(&policy, &mut clock, || {
+        attempts += 1;
+        Err(Error::Unavailable)
+    });
+
+    assert_eq!(result, Err(Error::Unavailable));
+    assert_eq!(attempts, 3);
+    assert_eq!(clock.sleeps.len(), 2);
+
+    /* zero attempts still calls the operation once */
+    let policy = RetryPolicy::new(0, Backoff::Fixed(millis(10)));
+    assert_eq!(retry_with_clock(&policy, &mut clock, failing(1)), Err(Error::Unavailable));
+}
+
+#[test]
+fn only_retryable_errors_are_retried() {
+
+    let mut clock = FakeClock::default();
+    let policy = RetryPolicy::new(5, Backoff::Fixed(millis(10)))
+        .retry_if(|error: &Error| *error == Error::Unavailable);
+
+    let mut errors = vec![Error::NotFound, Error::Unavailable];
+    let result: Result<(), Error> = retry_with_clock(&policy, &mut clock, || Err(errors.pop().unwrap()));
+
+    assert_eq!(result, Err(Error::NotFound));
+    assert_eq!(clock.sleeps, vec![millis(10)]);
+}
+
+#[test]
+fn no_retry_starts_after_the_timeout() {
+
+    let mut clock = FakeClock::default();
+    let policy = RetryPolicy::new(10, Backoff::Fixed(millis(400))).timeout(millis(1000));
+
+    /* 2 retries fit into the timeout, the third one would end after it */
+    assert_eq!(retry_with_clock(&policy, &mut clock, failing(5)), Err(Error::Unavailable));
+    assert_eq!(clock.sleeps.len(), 2);
+    assert!(clock.now <= millis(1000));
+}
@@ -0,0 +1,106 @@
+/* compares the array of structs layout (`Vec<Particle>`)
+   with the struct of arrays layout (`Particles`) of the particles module:
+   throughput of the iterations reading one field, four fields,
+   and updating the positions, for 10^3 to 10^6 particles
+
+   `cargo run --release -p compound_types --bin layout_benchmark -- [max power of ten]`,
+   the default max power of ten is 6 */
+
+use std::env;
+use std::hint::black_box;
+use std::time::Instant;
+
+use compound_types::particles::{
+    self,
+    Particle,
+    Particles,
+};
+
+const DEFAULT_MAX_POWER: u32 = 6;
+
+/* every measure processes about this amount of particles, whatever the size */
+const PROCESSED_PARTICLES: usize = 100_000_000;
+
+const TIME_STEP: f32 = 0.01;
+
+/// Runs the operation enough times, returns the millions of particles processed per second
+fn throughput<F: FnMut()>(
+    count: usize,
+    mut operation: F,
+) -> f64
+{
+    let rounds = (PROCESSED_PARTICLES / count).max(1);
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        operation();
+    }
+
+    (rounds * count) as f64 / start.elapsed().as_secs_f64() / 1_000_000.0
+}
+
+fn print_throughput(
+    operation: &str,
+    count: usize,
+    array_of_structs: f64,
+    struct_of_arrays: f64,
+) {
+    println!(
+        "{:<16} {:>10} {:>12.0} {:>12.0} {:>8.2}",
+        operation,
+        count,
+        array_of_structs,
+        struct_of_arrays,
+        struct_of_arrays / array_of_structs,
+    );
+}
+
+fn main() {
+
+    let max_power = env::args()
+        .nth(1)
+        .and_then(|power| power.parse().ok())
+        .unwrap_or(DEFAULT_MAX_POWER);
+
+    println!("particles per microsecond, {} bytes per particle", size_of::<Particle>());
+    println!(
+        "{:<16} {:>10} {:>12} {:>12} {:>8}",
+        "operation", "particles", "AoS", "SoA", "SoA/AoS",
+    );
+
+    for power in 3..=max_power {
+
+        let count = 10usize.pow(power);
+        let mut array_of_structs = particles::generate(count);
+        let mut struct_of_arrays: Particles = array_of_structs.iter().copied().collect();
+
+        print_throughput(
+            "mean x",
+            count,
+            throughput(count, || {
+                black_box(particles::mean_x(black_box(&array_of_structs)));
+            }),
+            throughput(count, || {
+                black_box(black_box(&struct_of_arrays).mean_x());
+            }),
+        );
+
+        print_throughput(
+            "kinetic energy",
+            count,
+            throughput(count, || {
+                black_box(particles::kinetic_energy(black_box(&array_of_structs)));
+            }),
+            throughput(count, || {
+                black_box(black_box(&struct_of_arrays).kinetic_energy());
+            }),
+        );
+
+        print_throughput(
+            "step",
+            count,
+            throughput(count, || particles::step(black_box(&mut array_of_structs), TIME_STEP)),
+            throughput(count, || black_box(&mut struct_of_arrays).step(TIME_STEP)),
+        );
+    }
+}
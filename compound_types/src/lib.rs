@@ -0,0 +1,104 @@
+pub mod matrix;
+pub mod particles;
+pub mod tuples;
+
+use matrix::Matrix;
+use particles::{
+    Particle,
+    Particles,
+};
+use tuples::{
+    Append,
+    Flatten,
+    Reverse,
+    TupleArray,
+    TupleLen,
+    TupleMap,
+    TupleZip,
+};
+
+#[allow(unused)]
+pub fn run() {
+
+    /* tuple with implicit types */
+    let tuple = (10, 10.5, 'a');
+    println!("{}", tuple.0);
+    println!("{}", tuple.1);
+    println!("{}", tuple.2);
+
+    /* tuple with explicit types */
+    let tuple: (u8, f32, char) = (10, 10.5, 'a');
+    println!("{}", tuple.0);
+    println!("{}", tuple.1);
+    println!("{}", tuple.2);
+
+    /* create new variable from tuple */
+    let a = tuple.0;
+    let b = tuple.1;
+    let c = tuple.2;
+    println!("{}", a);
+    println!("{}", b);
+    println!("{}", c);
+
+    /* tuple is affected my mutability */
+    let tuple = (10, 20);
+    //error: tuple.0 = 5;
+
+    let mut tuple = (10, 20);
+    tuple.0 = 50;
+    println!("{}", tuple.0);
+
+    /* declare a chunk of variables on the stack and access them */
+    let array = [1, 2, 3, 4];
+    println!("{}", array[0]);
+    println!("{}", array[1]);
+
+    /* arrays are affected by mutability */
+    let array = [1, 2];
+    //error: array[0] = 10;
+
+    let mut array = [1, 2];
+    array[0] = 10;
+    println!("{}", array[0]);
+
+    /* explicit array types (contains f32 and size is 3) */
+    let array: [f32; 3] = [1.0, 2.0, 3.0];
+    println!("{}", array[0]);
+
+    /* a matrix is an array of arrays, its dimensions are const generics,
+       so matrices with wrong dimensions cannot be added or multiplied */
+    let first = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let second = Matrix::new([[1, 0], [0, 1], [1, 1]]);
+    let product = first * second;
+    println!("{}", product[(1, 0)]); // 10
+    println!("{:?}", product.into_rows()); // [[4, 5], [10, 11]]
+    println!("{:?}", first.transpose().into_rows()); // [[1, 4], [2, 5], [3, 6]]
+    println!("{}", product + Matrix::identity() == Matrix::new([[5, 5], [10, 12]])); // true
+    //error: let sum = first + second;
+
+    /* tuple traits are implemented by macros for every arity from 1 to 12 */
+    let tuple = (1, 2.5, true);
+    println!("{}", tuple.len()); // 3
+    println!("{:?}", tuple.reverse()); // (true, 2.5, 1)
+    println!("{:?}", tuple.append(-1)); // (1, 2.5, true, -1)
+    println!("{:?}", (1, 2, 3).map(|item| item * 10)); // (10, 20, 30)
+    println!("{:?}", (1, 2, 3).into_array()); // [1, 2, 3]
+    println!("{:?}", (1, 2).zip((true, false))); // ((1, true), (2, false))
+    println!("{:?}", ((1, 2), (3,), (4, 5)).flatten()); // (1, 2, 3, 4, 5)
+
+    /* an array of structs keeps the fields of every item together,
+       a struct of arrays keeps every field in its own vector */
+    let particle = Particle {
+        x: 1.0,
+        vx: 2.0,
+        mass: 1.0,
+        ..Particle::default()
+    };
+    let mut array_of_structs = vec![particle; 3];
+    let mut struct_of_arrays: Particles = array_of_structs.iter().copied().collect();
+    particles::step(&mut array_of_structs, 0.5);
+    struct_of_arrays.step(0.5);
+    println!("{}", array_of_structs[0].x); // 2
+    println!("{:?}", struct_of_arrays.xs); // [2.0, 2.0, 2.0]
+    println!("{}", struct_of_arrays.get(0) == Some(array_of_structs[0])); // true
+}
@@ -0,0 +1,199 @@
+/* a matrix stored as an array of rows, every row is an array;
+   the amount of rows and columns are const generics,
+   they are part of the type, so the dimensions are checked at compilation:
+
+   - two matrices can only be added if they have the same dimensions,
+   - a R x K matrix can only be multiplied by a K x C matrix,
+     the result is a R x C matrix */
+
+use std::array;
+use std::fmt;
+use std::ops::{
+    Add,
+    Index,
+    IndexMut,
+    Mul,
+};
+
+/// Matrix of R rows and C columns
+///
+/// Matrices of different dimensions cannot be added:
+///
+/// ```compile_fail,E0308
+/// use compound_types::matrix::Matrix;
+///
+/// let first = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let second = Matrix::new([[1, 2], [3, 4], [5, 6]]);
+/// let sum = first + second;
+/// ```
+///
+/// The columns of the first matrix of a product must be the rows of the second one:
+///
+/// ```compile_fail,E0308
+/// use compound_types::matrix::Matrix;
+///
+/// let first = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+/// let product = first * first;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    rows: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+
+    pub fn new(rows: [[T; C]; R]) -> Matrix<T, R, C> {
+        Matrix {
+            rows,
+        }
+    }
+
+    /// Creates the matrix by calling the function with the row and the column of every item
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut function: F) -> Matrix<T, R, C> {
+        Matrix {
+            rows: array::from_fn(|row| array::from_fn(|column| function(row, column))),
+        }
+    }
+
+    /* the dimensions are known at compilation, these are constant functions */
+
+    pub const fn rows(&self) -> usize {
+        R
+    }
+
+    pub const fn columns(&self) -> usize {
+        C
+    }
+
+    pub fn into_rows(self) -> [[T; C]; R] {
+        self.rows
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+
+    /// Returns the matrix with the rows as columns,
+    /// the dimensions are swapped into the type of the result
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_fn(|row, column| self.rows[column][row])
+    }
+}
+
+/* only a square matrix has an identity */
+impl<T: Default + From<u8>, const N: usize> Matrix<T, N, N> {
+
+    pub fn identity() -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, column| {
+            if row == column {
+                T::from(1)
+            } else {
+                T::default()
+            }
+        })
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for Matrix<T, R, C> {
+
+    fn default() -> Matrix<T, R, C> {
+        Matrix::from_fn(|_, _| T::default())
+    }
+}
+
+/* both matrices have the same R and C */
+impl<T: Copy + Add<Output = T>, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Matrix<T, R, C>;
+
+    fn add(
+        self,
+        other: Matrix<T, R, C>,
+    ) -> Matrix<T, R, C>
+    {
+        Matrix::from_fn(|row, column| self.rows[row][column] + other.rows[row][column])
+    }
+}
+
+/* the columns of the first matrix and the rows of the second one are both K */
+impl<T, const R: usize, const K: usize, const C: usize> Mul<Matrix<T, K, C>> for Matrix<T, R, K>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn mul(
+        self,
+        other: Matrix<T, K, C>,
+    ) -> Matrix<T, R, C>
+    {
+        Matrix::from_fn(|row, column| {
+            (0..K).fold(T::default(), |sum, index| {
+                sum + self.rows[row][index] * other.rows[index][column]
+            })
+        })
+    }
+}
+
+/* the items are accessed with matrix[(row, column)],
+   out of bounds indexes panic like arrays */
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(
+        &self,
+        (row, column): (usize, usize),
+    ) -> &T
+    {
+        &self.rows[row][column]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+
+    fn index_mut(
+        &mut self,
+        (row, column): (usize, usize),
+    ) -> &mut T
+    {
+        &mut self.rows[row][column]
+    }
+}
+
+/* one line per row, the columns aligned on the right:
+
+   | 1  2 |
+   | 3 10 | */
+impl<T: fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        let items: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|item| item.to_string()).collect())
+            .collect();
+
+        let width = items
+            .iter()
+            .flatten()
+            .map(|item| item.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        for (index, row) in items.iter().enumerate() {
+            if index > 0 {
+                writeln!(formatter)?;
+            }
+
+            write!(formatter, "|")?;
+            for item in row {
+                write!(formatter, " {:>width$}", item, width = width)?;
+            }
+            write!(formatter, " |")?;
+        }
+
+        Ok(())
+    }
+}
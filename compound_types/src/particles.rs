@@ -0,0 +1,237 @@
+/* the same particles stored with two memory layouts:
+
+   - array of structs (AoS), `Vec<Particle>`: the fields of one particle
+     are next to each other, reading only one field of every particle
+     also loads the other ones into the cache,
+   - struct of arrays (SoA), `Particles`: one vector per field,
+     reading one field of every particle reads contiguous memory only,
+     the loops over one field are easy to vectorize
+
+   the SoA form is derived from the struct by the `soa!` macro,
+   `cargo run --release -p compound_types --bin layout_benchmark` compares both */
+
+/// Declares a struct and its struct of arrays form, with one vector per field;
+/// every field is given with the name of its vector:
+///
+/// ```
+/// compound_types::soa! {
+///     #[derive(Clone, Copy, Debug, PartialEq)]
+///     pub struct Point => Points {
+///         pub x => xs: f32,
+///         pub y => ys: f32,
+///     }
+/// }
+///
+/// let points: Points = vec![Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }].into_iter().collect();
+/// assert_eq!(points.xs, vec![1.0, 3.0]);
+/// assert_eq!(points.get(1), Some(Point { x: 3.0, y: 4.0 }));
+/// ```
+/* the paths are absolute, the macro works wherever the names of the prelude
+   are shadowed or missing (`#![no_implicit_prelude]`) */
+#[macro_export]
+macro_rules! soa {
+    (
+        $(#[$meta:meta])*
+        $visibility:vis struct $name:ident => $soa:ident {
+            $($field_visibility:vis $field:ident => $vector:ident: $type:ty),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $visibility struct $name {
+            $($field_visibility $field: $type),+
+        }
+
+        #[doc = ::std::concat!("Struct of arrays form of `", ::std::stringify!($name), "`, all the vectors have the same length")]
+        #[derive(
+            ::std::clone::Clone,
+            ::std::fmt::Debug,
+            ::std::default::Default,
+            ::std::cmp::PartialEq,
+        )]
+        $visibility struct $soa {
+            $(pub $vector: ::std::vec::Vec<$type>),+
+        }
+
+        impl $soa {
+
+            pub fn new() -> $soa {
+                <$soa as ::std::default::Default>::default()
+            }
+
+            pub fn with_capacity(capacity: usize) -> $soa {
+                $soa {
+                    $($vector: ::std::vec::Vec::with_capacity(capacity)),+
+                }
+            }
+
+            /* every vector has the length, the first one is returned */
+            pub fn len(&self) -> usize {
+                [$(self.$vector.len()),+][0]
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Splits the item into the vectors
+            pub fn push(
+                &mut self,
+                item: $name,
+            ) {
+                $(self.$vector.push(item.$field);)+
+            }
+
+            /// Gathers the fields of the item from the vectors
+            pub fn get(
+                &self,
+                index: usize,
+            ) -> ::std::option::Option<$name>
+            {
+                if index >= self.len() {
+                    return ::std::option::Option::None;
+                }
+
+                ::std::option::Option::Some($name {
+                    $($field: ::std::clone::Clone::clone(&self.$vector[index])),+
+                })
+            }
+
+            pub fn iter(&self) -> impl ::std::iter::Iterator<Item = $name> + '_ {
+                ::std::iter::Iterator::map(0..self.len(), move |index| self.get(index).unwrap())
+            }
+        }
+
+        impl ::std::iter::FromIterator<$name> for $soa {
+
+            fn from_iter<I: ::std::iter::IntoIterator<Item = $name>>(items: I) -> $soa {
+                let mut soa = $soa::new();
+                for item in items {
+                    soa.push(item);
+                }
+                soa
+            }
+        }
+    };
+}
+
+soa! {
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct Particle => Particles {
+        pub x => xs: f32,
+        pub y => ys: f32,
+        pub z => zs: f32,
+        pub vx => vxs: f32,
+        pub vy => vys: f32,
+        pub vz => vzs: f32,
+        pub mass => masses: f32,
+        pub charge => charges: f32,
+    }
+}
+
+/* the operations are written for both layouts,
+   the items are processed in the same order, so the results are equal */
+
+impl Particle {
+
+    /// Moves the particle by its velocity during the time step
+    pub fn step(
+        &mut self,
+        time: f32,
+    ) {
+        self.x += self.vx * time;
+        self.y += self.vy * time;
+        self.z += self.vz * time;
+    }
+
+    pub fn kinetic_energy(&self) -> f32 {
+        0.5 * self.mass * (self.vx * self.vx + self.vy * self.vy + self.vz * self.vz)
+    }
+}
+
+/// Moves every particle of the AoS layout
+pub fn step(
+    particles: &mut [Particle],
+    time: f32,
+) {
+    for particle in particles {
+        particle.step(time);
+    }
+}
+
+/// Mean of the x positions of the AoS layout, reads one field out of eight
+pub fn mean_x(particles: &[Particle]) -> f32 {
+
+    if particles.is_empty() {
+        return 0.0;
+    }
+
+    particles.iter().map(|particle| particle.x).sum::<f32>() / particles.len() as f32
+}
+
+/// Kinetic energy of the AoS layout, reads four fields out of eight
+pub fn kinetic_energy(particles: &[Particle]) -> f32 {
+    particles.iter().map(Particle::kinetic_energy).sum()
+}
+
+impl Particles {
+
+    /// Moves every particle, one axis after the other
+    pub fn step(
+        &mut self,
+        time: f32,
+    ) {
+        for (position, velocity) in [
+            (&mut self.xs, &self.vxs),
+            (&mut self.ys, &self.vys),
+            (&mut self.zs, &self.vzs),
+        ] {
+            for (position, velocity) in position.iter_mut().zip(velocity) {
+                *position += velocity * time;
+            }
+        }
+    }
+
+    /// Mean of the x positions, reads one vector only
+    pub fn mean_x(&self) -> f32 {
+
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        self.xs.iter().sum::<f32>() / self.len() as f32
+    }
+
+    pub fn kinetic_energy(&self) -> f32 {
+        self.masses
+            .iter()
+            .zip(&self.vxs)
+            .zip(&self.vys)
+            .zip(&self.vzs)
+            .map(|(((mass, vx), vy), vz)| 0.5 * mass * (vx * vx + vy * vy + vz * vz))
+            .sum()
+    }
+}
+
+/// Returns the given amount of particles, always the same ones
+/// (pseudo-random values from a linear congruential generator)
+pub fn generate(count: usize) -> Vec<Particle> {
+
+    let mut state: u64 = 42;
+    let mut random = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 40) as f32 / (1u64 << 24) as f32
+    };
+
+    (0..count)
+        .map(|_| Particle {
+            x: random() * 100.0,
+            y: random() * 100.0,
+            z: random() * 100.0,
+            vx: random() - 0.5,
+            vy: random() - 0.5,
+            vz: random() - 0.5,
+            mass: random() + 1.0,
+            charge: random() * 2.0 - 1.0,
+        })
+        .collect()
+}
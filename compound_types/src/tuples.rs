@@ -0,0 +1,313 @@
+/* traits for tuples of 1 to 12 items;
+   every tuple arity is a different type, so the traits are implemented
+   once per arity, the implementations are generated by macros
+   (the standard library does the same for `PartialEq`, `Debug`...) */
+
+/// Amount of items of a tuple, known at compilation
+pub trait TupleLen {
+    const LEN: usize;
+
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+}
+
+/// Tuple with items of the same type, that can be transformed one by one
+pub trait TupleMap<T> {
+
+    /// Same tuple, with U items
+    type Output<U>;
+
+    /// Calls the function on every item, from the first one to the last one
+    fn map<U, F: FnMut(T) -> U>(
+        self,
+        function: F,
+    ) -> Self::Output<U>;
+}
+
+/// Tuple with items of the same type, that can be converted to and from an array
+/// (the standard library also gives `From` implementations between them)
+pub trait TupleArray<T>: Sized {
+    type Array;
+
+    fn into_array(self) -> Self::Array;
+
+    fn from_array(array: Self::Array) -> Self;
+}
+
+/// Adds an item at the end of the tuple
+pub trait Append<V> {
+    type Output;
+
+    fn append(
+        self,
+        value: V,
+    ) -> Self::Output;
+}
+
+/// Adds an item at the start of the tuple
+pub trait Prepend<V> {
+    type Output;
+
+    fn prepend(
+        self,
+        value: V,
+    ) -> Self::Output;
+}
+
+/// Reverses the order of the items, with their types
+pub trait Reverse {
+    type Output;
+
+    fn reverse(self) -> Self::Output;
+}
+
+/// Pairs the items of two tuples of the same arity, like `Iterator::zip`
+pub trait TupleZip<Other> {
+    type Output;
+
+    fn zip(
+        self,
+        other: Other,
+    ) -> Self::Output;
+}
+
+/// Adds the items of the other tuple at the end of the tuple
+pub trait Concat<Other> {
+    type Output;
+
+    fn concat(
+        self,
+        other: Other,
+    ) -> Self::Output;
+}
+
+/// Concatenates the tuples of a tuple of tuples,
+/// one level only, like `Iterator::flatten`
+pub trait Flatten {
+    type Output;
+
+    fn flatten(self) -> Self::Output;
+}
+
+impl TupleLen for () {
+    const LEN: usize = 0;
+}
+
+impl<V> Append<V> for () {
+    type Output = (V,);
+
+    fn append(
+        self,
+        value: V,
+    ) -> (V,)
+    {
+        (value,)
+    }
+}
+
+impl<V> Prepend<V> for () {
+    type Output = (V,);
+
+    fn prepend(
+        self,
+        value: V,
+    ) -> (V,)
+    {
+        (value,)
+    }
+}
+
+impl<Other: TupleLen> Concat<Other> for () {
+    type Output = Other;
+
+    fn concat(
+        self,
+        other: Other,
+    ) -> Other
+    {
+        other
+    }
+}
+
+impl Flatten for () {
+    type Output = ();
+
+    fn flatten(self) {}
+}
+
+/* returns the second token, used to repeat a type or a value once per item */
+macro_rules! replace {
+    ($_item:ident, $with:tt) => {
+        $with
+    };
+}
+
+/* implements the traits for one arity, given the names of its types;
+   the names of the types are also the names of the items when the tuple is destructured */
+macro_rules! tuple_impls {
+    ($($name:ident)+) => {
+        impl<$($name),+> TupleLen for ($($name,)+) {
+            const LEN: usize = 0 $(+ replace!($name, 1))+;
+        }
+
+        impl<T> TupleMap<T> for ($(replace!($name, T),)+) {
+            type Output<U> = ($(replace!($name, U),)+);
+
+            #[allow(non_snake_case)]
+            fn map<U, F: FnMut(T) -> U>(
+                self,
+                mut function: F,
+            ) -> Self::Output<U>
+            {
+                let ($($name,)+) = self;
+                ($(function($name),)+)
+            }
+        }
+
+        impl<T> TupleArray<T> for ($(replace!($name, T),)+) {
+            type Array = [T; 0 $(+ replace!($name, 1))+];
+
+            #[allow(non_snake_case)]
+            fn into_array(self) -> Self::Array {
+                let ($($name,)+) = self;
+                [$($name),+]
+            }
+
+            #[allow(non_snake_case)]
+            fn from_array(array: Self::Array) -> Self {
+                let [$($name),+] = array;
+                ($($name,)+)
+            }
+        }
+
+        impl<$($name,)+ V> Append<V> for ($($name,)+) {
+            type Output = ($($name,)+ V,);
+
+            #[allow(non_snake_case)]
+            fn append(
+                self,
+                value: V,
+            ) -> Self::Output
+            {
+                let ($($name,)+) = self;
+                ($($name,)+ value,)
+            }
+        }
+
+        impl<$($name,)+ V> Prepend<V> for ($($name,)+) {
+            type Output = (V, $($name,)+);
+
+            #[allow(non_snake_case)]
+            fn prepend(
+                self,
+                value: V,
+            ) -> Self::Output
+            {
+                let ($($name,)+) = self;
+                (value, $($name,)+)
+            }
+        }
+
+        reverse_impl!([$($name)+] [$($name)+] []);
+        recursive_impls!($($name)+);
+    };
+}
+
+/* implements the traits defined from the ones of the tuple without its first item:
+   the first item is prepended to the result of the rest of the tuple,
+   so no implementation is needed per pair of arities */
+macro_rules! recursive_impls {
+    ($first:ident $($rest:ident)*) => {
+        impl<$first, $($rest,)* Other> Concat<Other> for ($first, $($rest,)*)
+        where
+            ($($rest,)*): Concat<Other>,
+            <($($rest,)*) as Concat<Other>>::Output: Prepend<$first>,
+        {
+            type Output = <<($($rest,)*) as Concat<Other>>::Output as Prepend<$first>>::Output;
+
+            #[allow(non_snake_case)]
+            fn concat(
+                self,
+                other: Other,
+            ) -> Self::Output
+            {
+                let ($first, $($rest,)*) = self;
+                ($($rest,)*).concat(other).prepend($first)
+            }
+        }
+
+        impl<$first, $($rest,)*> Flatten for ($first, $($rest,)*)
+        where
+            ($($rest,)*): Flatten,
+            $first: Concat<<($($rest,)*) as Flatten>::Output>,
+        {
+            type Output = <$first as Concat<<($($rest,)*) as Flatten>::Output>>::Output;
+
+            #[allow(non_snake_case)]
+            fn flatten(self) -> Self::Output {
+                let ($first, $($rest,)*) = self;
+                $first.concat(($($rest,)*).flatten())
+            }
+        }
+    };
+}
+
+/* moves the names one by one from the second list to the front of the third one,
+   then implements `Reverse` with the first list and the reversed one */
+macro_rules! reverse_impl {
+    ([$($name:ident)+] [] [$($reversed:ident)+]) => {
+        impl<$($name),+> Reverse for ($($name,)+) {
+            type Output = ($($reversed,)+);
+
+            #[allow(non_snake_case)]
+            fn reverse(self) -> Self::Output {
+                let ($($name,)+) = self;
+                ($($reversed,)+)
+            }
+        }
+    };
+    ([$($name:ident)+] [$first:ident $($rest:ident)*] [$($reversed:ident)*]) => {
+        reverse_impl!([$($name)+] [$($rest)*] [$first $($reversed)*]);
+    };
+}
+
+/* implements the traits for the given arity, then for the one with an item less */
+macro_rules! tuples {
+    () => {};
+    ($first:ident $($rest:ident)*) => {
+        tuple_impls!($first $($rest)*);
+        tuples!($($rest)*);
+    };
+}
+
+/* implements `TupleZip` for the given arity, then for the one with an item less;
+   every type of the tuple is given with the type of the same item of the other tuple */
+macro_rules! zip_impls {
+    () => {};
+    ($name:ident $other:ident $(, $names:ident $others:ident)*) => {
+        impl<$name, $($names,)* $other, $($others,)*> TupleZip<($other, $($others,)*)> for ($name, $($names,)*) {
+            type Output = (($name, $other), $(($names, $others),)*);
+
+            #[allow(non_snake_case)]
+            fn zip(
+                self,
+                other: ($other, $($others,)*),
+            ) -> Self::Output
+            {
+                let ($name, $($names,)*) = self;
+                let ($other, $($others,)*) = other;
+                (($name, $other), $(($names, $others),)*)
+            }
+        }
+
+        zip_impls!($($names $others),*);
+    };
+}
+
+tuples!(A B C D E F G H I J K L);
+zip_impls!(A M, B N, C O, D P, E Q, F R, G S, H T, I U, J V, K W, L X);
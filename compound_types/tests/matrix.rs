@@ -0,0 +1,83 @@
+use compound_types::matrix::Matrix;
+
+#[test]
+fn matrices_of_the_same_dimensions_are_added_item_by_item() {
+
+    let first = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let second = Matrix::new([[10, 20, 30], [40, 50, 60]]);
+
+    assert_eq!((first + second).into_rows(), [[11, 22, 33], [44, 55, 66]]);
+}
+
+#[test]
+fn multiplication_changes_the_dimensions() {
+
+    let first = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let second = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+
+    let product: Matrix<i32, 2, 2> = first * second;
+    assert_eq!(product.into_rows(), [[58, 64], [139, 154]]);
+
+    /* a column multiplied by a row */
+    let column = Matrix::new([[1], [2]]);
+    let row = Matrix::new([[3, 4, 5]]);
+    let product = column * row;
+    assert_eq!((product.rows(), product.columns()), (2, 3));
+    assert_eq!(product.into_rows(), [[3, 4, 5], [6, 8, 10]]);
+}
+
+#[test]
+fn identity_is_neutral_for_multiplication() {
+
+    let matrix = Matrix::new([[1.5, 2.0], [-3.0, 4.25]]);
+
+    assert_eq!(matrix * Matrix::identity(), matrix);
+    assert_eq!(Matrix::identity() * matrix, matrix);
+    assert_eq!(Matrix::<u8, 3, 3>::identity().into_rows(), [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+}
+
+#[test]
+fn transpose_swaps_rows_and_columns() {
+
+    let matrix = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let transposed: Matrix<i32, 3, 2> = matrix.transpose();
+
+    assert_eq!(transposed.into_rows(), [[1, 4], [2, 5], [3, 6]]);
+    assert_eq!(transposed.transpose(), matrix);
+
+    /* (A B)t = Bt At */
+    let other = Matrix::new([[1, 0], [2, 1], [0, 3]]);
+    assert_eq!((matrix * other).transpose(), other.transpose() * matrix.transpose());
+}
+
+#[test]
+fn items_are_indexed_by_row_and_column() {
+
+    let mut matrix = Matrix::<i32, 2, 3>::default();
+    matrix[(1, 2)] = 7;
+    matrix[(0, 1)] += 3;
+
+    assert_eq!(matrix[(1, 2)], 7);
+    assert_eq!(matrix.into_rows(), [[0, 3, 0], [0, 0, 7]]);
+
+    let from_fn = Matrix::<usize, 2, 2>::from_fn(|row, column| row * 10 + column);
+    assert_eq!(from_fn[(1, 0)], 10);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds_index_panics() {
+
+    let matrix = Matrix::new([[1, 2], [3, 4]]);
+    let _ = matrix[(2, 0)];
+}
+
+#[test]
+fn display_aligns_the_columns() {
+
+    let matrix = Matrix::new([[1, 2], [3, 10]]);
+    assert_eq!(matrix.to_string(), "|  1  2 |\n|  3 10 |");
+
+    let single = Matrix::new([[-5]]);
+    assert_eq!(single.to_string(), "| -5 |");
+}
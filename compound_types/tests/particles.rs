@@ -0,0 +1,123 @@
+use compound_types::particles::{
+    self,
+    Particle,
+    Particles,
+};
+
+compound_types::soa! {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Named => NamedColumns {
+        id => ids: u32,
+        name => names: String,
+    }
+}
+
+/* the macro does not rely on the names of the prelude */
+mod without_prelude {
+    #![no_implicit_prelude]
+
+    /* shadows the names the macro must not use */
+    #[allow(dead_code)]
+    struct Vec;
+    #[allow(dead_code)]
+    struct Option;
+
+    ::compound_types::soa! {
+        #[derive(::std::clone::Clone, ::std::fmt::Debug, ::std::cmp::PartialEq)]
+        pub struct Pair => Pairs {
+            pub first => firsts: u8,
+            pub second => seconds: u8,
+        }
+    }
+}
+
+#[test]
+fn the_macro_works_without_the_prelude() {
+
+    use without_prelude::{
+        Pair,
+        Pairs,
+    };
+
+    let pairs: Pairs = (0..3).map(|index| Pair { first: index, second: index * 2 }).collect();
+    assert_eq!(pairs.seconds, [0, 2, 4]);
+    assert_eq!(pairs.get(2), Some(Pair { first: 2, second: 4 }));
+    assert!(pairs.iter().eq(pairs.clone().iter()));
+}
+
+#[test]
+fn the_macro_declares_the_struct_and_its_columns() {
+
+    let mut columns = NamedColumns::with_capacity(2);
+    assert!(columns.is_empty());
+
+    columns.push(Named { id: 1, name: String::from("first") });
+    columns.push(Named { id: 2, name: String::from("second") });
+
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns.ids, vec![1, 2]);
+    assert_eq!(columns.names, vec!["first", "second"]);
+
+    /* the fields are cloned out of the columns */
+    assert_eq!(columns.get(1), Some(Named { id: 2, name: String::from("second") }));
+    assert_eq!(columns.get(2), None);
+}
+
+#[test]
+fn both_layouts_hold_the_same_particles() {
+
+    let array_of_structs = particles::generate(100);
+    let struct_of_arrays: Particles = array_of_structs.iter().copied().collect();
+
+    assert_eq!(struct_of_arrays.len(), 100);
+    assert_eq!(struct_of_arrays.xs[42], array_of_structs[42].x);
+    assert_eq!(struct_of_arrays.charges[99], array_of_structs[99].charge);
+    assert_eq!(struct_of_arrays.iter().collect::<Vec<Particle>>(), array_of_structs);
+}
+
+#[test]
+fn both_layouts_compute_the_same_results() {
+
+    let mut array_of_structs = particles::generate(1000);
+    let mut struct_of_arrays: Particles = array_of_structs.iter().copied().collect();
+
+    /* the items are processed in the same order, the floats are exactly equal */
+    assert_eq!(particles::mean_x(&array_of_structs), struct_of_arrays.mean_x());
+    assert_eq!(particles::kinetic_energy(&array_of_structs), struct_of_arrays.kinetic_energy());
+
+    for _ in 0..10 {
+        particles::step(&mut array_of_structs, 0.1);
+        struct_of_arrays.step(0.1);
+    }
+
+    assert_eq!(struct_of_arrays.iter().collect::<Vec<Particle>>(), array_of_structs);
+    assert_eq!(particles::mean_x(&array_of_structs), struct_of_arrays.mean_x());
+}
+
+#[test]
+fn step_moves_by_the_velocity() {
+
+    let particle = Particle {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        vx: 10.0,
+        vy: -10.0,
+        vz: 0.0,
+        mass: 2.0,
+        charge: 0.0,
+    };
+    let mut struct_of_arrays: Particles = vec![particle].into_iter().collect();
+    struct_of_arrays.step(0.5);
+
+    let moved = struct_of_arrays.get(0).unwrap();
+    assert_eq!((moved.x, moved.y, moved.z), (6.0, -3.0, 3.0));
+    assert_eq!(struct_of_arrays.kinetic_energy(), 200.0);
+}
+
+#[test]
+fn empty_layouts_have_a_zero_mean() {
+
+    assert_eq!(particles::mean_x(&[]), 0.0);
+    assert_eq!(Particles::new().mean_x(), 0.0);
+}
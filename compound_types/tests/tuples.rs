@@ -0,0 +1,192 @@
+use compound_types::tuples::{
+    Append,
+    Concat,
+    Flatten,
+    Prepend,
+    Reverse,
+    TupleArray,
+    TupleLen,
+    TupleMap,
+    TupleZip,
+};
+
+fn round_trip<T: TupleArray<u32>>(tuple: T) -> T {
+    T::from_array(tuple.into_array())
+}
+
+/* checks every trait on a tuple of u32 of the given arity */
+macro_rules! check_arity {
+    ($len:expr; $($value:expr),+) => {{
+        let tuple = ($($value,)+);
+        let array: [u32; $len] = [$($value),+];
+
+        assert_eq!(tuple.len(), $len);
+        assert!(!tuple.is_empty());
+
+        assert_eq!(tuple.into_array(), array);
+        assert_eq!(round_trip(tuple), tuple);
+
+        assert_eq!(tuple.map(|item| item * 10), ($($value * 10,)+));
+        assert_eq!(tuple.map(|item| item.to_string()).into_array(), array.map(|item| item.to_string()));
+
+        let mut reversed = array;
+        reversed.reverse();
+        assert_eq!(tuple.reverse().into_array(), reversed);
+        assert_eq!(tuple.reverse().reverse(), tuple);
+
+        assert_eq!(tuple.zip(tuple.map(|item| item * 10)), ($(($value, $value * 10),)+));
+        let pairs = tuple.zip(tuple.map(|item| item.to_string()));
+        assert_eq!(pairs.map(|(item, text)| item.to_string() == text).into_array(), [true; $len]);
+
+        assert_eq!(tuple.concat(()), tuple);
+        assert_eq!(().concat(tuple), tuple);
+        assert_eq!((tuple,).flatten(), tuple);
+        assert_eq!(((), tuple, ()).flatten(), tuple);
+    }};
+}
+
+/* a tuple of one-item tuples, flattened into the tuple of the items */
+macro_rules! check_flatten {
+    ($($value:expr),+) => {{
+        let nested = ($(($value,),)+);
+        assert_eq!(nested.flatten(), ($($value,)+));
+        assert_eq!(nested.len(), nested.flatten().len());
+    }};
+}
+
+/* tuples of 13 items have no `PartialEq`, so the arities up to 11 are checked here */
+macro_rules! check_growth {
+    ($($value:expr),+) => {{
+        let tuple = ($($value,)+);
+
+        assert_eq!(tuple.append(100), ($($value,)+ 100,));
+        assert_eq!(tuple.prepend(0), (0, $($value,)+));
+        assert_eq!(tuple.append('z').len(), tuple.len() + 1);
+    }};
+}
+
+#[test]
+fn every_arity_implements_the_traits() {
+
+    check_arity!(1; 1);
+    check_arity!(2; 1, 2);
+    check_arity!(3; 1, 2, 3);
+    check_arity!(4; 1, 2, 3, 4);
+    check_arity!(5; 1, 2, 3, 4, 5);
+    check_arity!(6; 1, 2, 3, 4, 5, 6);
+    check_arity!(7; 1, 2, 3, 4, 5, 6, 7);
+    check_arity!(8; 1, 2, 3, 4, 5, 6, 7, 8);
+    check_arity!(9; 1, 2, 3, 4, 5, 6, 7, 8, 9);
+    check_arity!(10; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    check_arity!(11; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+    check_arity!(12; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+}
+
+#[test]
+fn every_arity_grows_by_one_item() {
+
+    check_growth!(1);
+    check_growth!(1, 2);
+    check_growth!(1, 2, 3);
+    check_growth!(1, 2, 3, 4);
+    check_growth!(1, 2, 3, 4, 5);
+    check_growth!(1, 2, 3, 4, 5, 6);
+    check_growth!(1, 2, 3, 4, 5, 6, 7);
+    check_growth!(1, 2, 3, 4, 5, 6, 7, 8);
+    check_growth!(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    check_growth!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    check_growth!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+
+    /* twelve items become thirteen, destructured to be checked */
+    let tuple = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+
+    let (first, .., twelfth, thirteenth) = tuple.append(13);
+    assert_eq!((first, twelfth, thirteenth), (1, 12, 13));
+
+    let (first, second, .., thirteenth) = tuple.prepend(0);
+    assert_eq!((first, second, thirteenth), (0, 1, 12));
+}
+
+#[test]
+fn every_outer_arity_flattens() {
+
+    check_flatten!(1);
+    check_flatten!(1, 2);
+    check_flatten!(1, 2, 3);
+    check_flatten!(1, 2, 3, 4);
+    check_flatten!(1, 2, 3, 4, 5);
+    check_flatten!(1, 2, 3, 4, 5, 6);
+    check_flatten!(1, 2, 3, 4, 5, 6, 7);
+    check_flatten!(1, 2, 3, 4, 5, 6, 7, 8);
+    check_flatten!(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    check_flatten!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    check_flatten!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+    check_flatten!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+}
+
+#[test]
+fn nested_tuples_of_any_arities_flatten() {
+
+    let flat: (u8, &str, f64, char, bool) = ((1u8, "two"), (), (3.0f64,), ('4', true)).flatten();
+    assert_eq!(flat, (1, "two", 3.0, '4', true));
+
+    /* one level only, the tuples into the items are kept */
+    assert_eq!(((1, (2, 3)), (4,)).flatten(), (1, (2, 3), 4));
+
+    assert_eq!((1, 2, 3).concat((4, 5, 6, 7, 8, 9, 10, 11, 12)), (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+    assert_eq!(().flatten(), ());
+}
+
+#[test]
+fn zip_pairs_items_of_different_types() {
+
+    let zipped: ((u8, char), (&str, bool)) = (1u8, "two").zip(('a', true));
+    assert_eq!(zipped, ((1, 'a'), ("two", true)));
+
+    /* zip then reverse: the pairs are reversed, not their content */
+    assert_eq!((1, 2, 3).zip(("a", "b", "c")).reverse(), ((3, "c"), (2, "b"), (1, "a")));
+}
+
+#[test]
+fn heterogeneous_tuples_keep_their_types() {
+
+    let tuple = (1u8, "two", 3.0f64, '4');
+    assert_eq!(tuple.len(), 4);
+    assert_eq!(<(u8, &str, f64, char) as TupleLen>::LEN, 4);
+
+    let reversed: (char, f64, &str, u8) = tuple.reverse();
+    assert_eq!(reversed, ('4', 3.0, "two", 1));
+
+    let appended: (u8, &str, f64, char, bool) = tuple.append(true);
+    assert!(appended.4);
+
+    let prepended: (Vec<i32>, u8, &str, f64, char) = tuple.prepend(vec![0]);
+    assert_eq!(prepended.0, vec![0]);
+
+    assert!(().is_empty());
+    assert_eq!(().append(1), (1,));
+    assert_eq!(().prepend(1), (1,));
+}
+
+#[test]
+fn map_calls_the_function_in_order() {
+
+    let mut calls = Vec::new();
+    let lengths = ("a", "bb", "ccc").map(|item| {
+        calls.push(item);
+        item.len()
+    });
+
+    assert_eq!(lengths, (1, 2, 3));
+    assert_eq!(calls, vec!["a", "bb", "ccc"]);
+}
+
+#[test]
+fn arrays_convert_to_tuples_of_owned_values() {
+
+    let names = [String::from("first"), String::from("second")];
+    let (first, second) = <(String, String)>::from_array(names);
+
+    assert_eq!(first, "first");
+    assert_eq!((first, second).into_array(), ["first", "second"]);
+}
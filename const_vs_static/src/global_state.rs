@@ -0,0 +1,146 @@
+/* safe alternatives to `static mut`:
+
+   a `static mut` can be read and written by many threads at the same time,
+   so every access is `unsafe` and taking a reference to it is refused
+   since the 2024 edition; the types below are safe to share between threads,
+   they can be stored into an immutable `static` and still be changed:
+
+   - an atomic integer for a counter,
+   - a `OnceLock` for a value initialized once, at runtime,
+   - a `LazyLock` around a `Mutex` for a collection changed at any time,
+   - a `thread_local!` for a value that every thread has its own copy of */
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{
+    AtomicI32,
+    Ordering,
+};
+use std::sync::{
+    LazyLock,
+    Mutex,
+    OnceLock,
+};
+
+/// Counter that can be stored into a `static` and incremented from any thread
+///
+/// `COUNTER += 1` on a `static mut` reads the value, then writes it:
+/// two threads can read the same value and one of the increments is lost;
+/// an atomic increment reads and writes at once
+#[derive(Debug, Default)]
+pub struct Counter {
+    value: AtomicI32,
+}
+
+impl Counter {
+
+    /* const, so it can initialize a static */
+    pub const fn new(value: i32) -> Counter {
+        Counter {
+            value: AtomicI32::new(value),
+        }
+    }
+
+    /// Adds one, returns the new value
+    pub fn increment(&self) -> i32 {
+        self.add(1)
+    }
+
+    /// Adds the amount, returns the new value
+    pub fn add(
+        &self,
+        amount: i32,
+    ) -> i32
+    {
+        /* the counter does not protect other data, no ordering is required */
+        self.value.fetch_add(amount, Ordering::Relaxed) + amount
+    }
+
+    pub fn get(&self) -> i32 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+/// Counts the calls of `config` from every thread
+pub static CONFIG_READS: Counter = Counter::new(0);
+
+/// Settings read from anywhere, known only at runtime
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub name: String,
+    pub workers: usize,
+}
+
+impl Default for Config {
+
+    fn default() -> Config {
+        Config {
+            name: String::from("default"),
+            workers: 1,
+        }
+    }
+}
+
+/* no value until the first initialization, never changed after it */
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Sets the configuration, only the first call succeeds,
+/// the other ones get their configuration back
+pub fn init_config(config: Config) -> Result<(), Config> {
+    CONFIG.set(config)
+}
+
+/// Returns the configuration, the default one if it was not set;
+/// when threads call it together before the initialization,
+/// only one of them initializes it and all of them get the same value
+pub fn config() -> &'static Config {
+    CONFIG_READS.increment();
+    CONFIG.get_or_init(Config::default)
+}
+
+/* created at the first access; the mutex gives the map to one thread at a time */
+static REGISTRY: LazyLock<Mutex<HashMap<String, u32>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Registers the value under the name, returns the previous value
+pub fn register(
+    name: &str,
+    value: u32,
+) -> Option<u32>
+{
+    REGISTRY.lock().unwrap().insert(name.to_string(), value)
+}
+
+/// Changes the registered value, the lock is kept from the read to the write,
+/// so no other thread changes the value meanwhile; returns the new value
+pub fn update<F: FnOnce(Option<u32>) -> u32>(
+    name: &str,
+    change: F,
+) -> u32
+{
+    let mut registry = REGISTRY.lock().unwrap();
+    let value = change(registry.get(name).copied());
+    registry.insert(name.to_string(), value);
+    value
+}
+
+pub fn lookup(name: &str) -> Option<u32> {
+    REGISTRY.lock().unwrap().get(name).copied()
+}
+
+thread_local! {
+
+    /* every thread has its own counter, no synchronization is needed */
+    static CALLS: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Counts a call of the current thread, returns the calls of this thread only
+pub fn record_call() -> u32 {
+    CALLS.with(|calls| {
+        calls.set(calls.get() + 1);
+        calls.get()
+    })
+}
+
+pub fn thread_calls() -> u32 {
+    CALLS.with(Cell::get)
+}
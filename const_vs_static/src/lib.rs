@@ -0,0 +1,108 @@
+pub mod global_state;
+
+use std::sync::atomic::{
+    AtomicI32,
+    Ordering,
+};
+use std::sync::Mutex;
+use std::thread;
+
+use global_state::Counter;
+
+/* constant stored into the code directly for future usage */
+#[allow(unused)]
+const STORED_IN_THE_CODE: i32 = 10;
+
+/* variable that exists in memory and that can be accessed from anywhere */
+static mut STORED_IN_MEMORY: i32 = 20;
+
+/* the same variable without unsafe code:
+   an atomic can be changed through an immutable static */
+static STORED_IN_ATOMIC: AtomicI32 = AtomicI32::new(20);
+
+static IMMUTABLE_VALUE: i32 = 50;
+
+fn print_mutable_value() {
+
+    /* static globals can be accessed from anywhere,
+       a "static mut" is copied: a reference to it is refused since the 2024 edition */
+    let value = unsafe { STORED_IN_MEMORY };
+    println!("{}", value); // 30
+    println!("{}", STORED_IN_ATOMIC.load(Ordering::Relaxed)); // 30
+
+    /* immutable static variables does not need to be called into unsafe block */
+    println!("{}", IMMUTABLE_VALUE); // 50
+}
+
+#[allow(unused)]
+pub fn run() {
+
+    /* "static" and "static mut" variables that exists once
+       for the entire program execution can be declared
+       outside or within a function */
+    static mut MUTABLE_VALUE: i32 = 30;
+    static VALUE: i32 = 20;
+
+    /* as many threads can access the variable at any time,
+       the modification of a mutable static variable
+       has to be performed into an unsafe block,
+       an atomic is modified safely */
+    unsafe {
+        STORED_IN_MEMORY = 30;
+    }
+    STORED_IN_ATOMIC.store(30, Ordering::Relaxed);
+
+    print_mutable_value();
+
+    /* a "static mut" counter: the compiler cannot check
+       that no other thread changes it at the same time,
+       two concurrent increments may give one (undefined behavior),
+       so it is only changed by this thread */
+    unsafe {
+        MUTABLE_VALUE += 1;
+    }
+    let value = unsafe { MUTABLE_VALUE };
+    println!("{}", value); // 31
+
+    /* the same counter shared by threads, safely:
+       an atomic increment reads and writes at once */
+    static VISITS: Counter = Counter::new(0);
+
+    /* a mutex increment is done while the lock is held */
+    static LOCKED_VISITS: Mutex<i32> = Mutex::new(0);
+
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..1000 {
+                    VISITS.increment();
+                    *LOCKED_VISITS.lock().unwrap() += 1;
+                }
+            });
+        }
+    });
+    println!("{}", VISITS.get()); // 4000
+    println!("{}", LOCKED_VISITS.lock().unwrap()); // 4000
+
+    /* instead of a "static mut" set at the start of the program,
+       the configuration is stored into a OnceLock:
+       it is initialized once, the other initializations fail */
+    let config = global_state::Config {
+        name: String::from("memo"),
+        workers: 4,
+    };
+    println!("{}", global_state::init_config(config.clone()).is_ok()); // true
+    println!("{}", global_state::init_config(config).is_ok()); // false
+    println!("{}", global_state::config().workers); // 4
+
+    /* the registry map is created at its first access */
+    global_state::register("answer", 41);
+    println!("{}", global_state::update("answer", |value| value.unwrap_or(0) + 1)); // 42
+
+    /* every thread counts its own calls */
+    global_state::record_call();
+    global_state::record_call();
+    let other_thread = thread::spawn(global_state::record_call).join().unwrap();
+    println!("{}", global_state::thread_calls()); // 2
+    println!("{}", other_thread); // 1
+}
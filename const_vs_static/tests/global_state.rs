@@ -0,0 +1,202 @@
+/* every test first shows what an unsynchronized global allows,
+   with a barrier forcing the threads to interleave the same way every time,
+   then that the safe type prevents it;
+   the unsynchronized versions use safe operations split in two,
+   exactly like `static mut` accesses but without undefined behavior */
+
+use std::collections::HashMap;
+use std::sync::atomic::{
+    AtomicBool,
+    AtomicI32,
+    Ordering,
+};
+use std::sync::{
+    Barrier,
+    Mutex,
+};
+use std::thread;
+
+use const_vs_static::global_state::{
+    self,
+    Config,
+    Counter,
+    CONFIG_READS,
+};
+
+const THREADS: usize = 8;
+
+#[test]
+fn split_increments_lose_updates_atomic_ones_do_not() {
+
+    /* `STATIC_MUT += 1`: both threads read 0, then both write 1 */
+    static UNSYNCHRONIZED: AtomicI32 = AtomicI32::new(0);
+    let barrier = Barrier::new(2);
+
+    thread::scope(|scope| {
+        for _ in 0..2 {
+            scope.spawn(|| {
+                let value = UNSYNCHRONIZED.load(Ordering::Relaxed);
+                barrier.wait();
+                UNSYNCHRONIZED.store(value + 1, Ordering::Relaxed);
+            });
+        }
+    });
+    assert_eq!(UNSYNCHRONIZED.load(Ordering::Relaxed), 1);
+
+    /* the same interleaving with an atomic increment */
+    static COUNTER: Counter = Counter::new(0);
+
+    thread::scope(|scope| {
+        for _ in 0..2 {
+            scope.spawn(|| {
+                barrier.wait();
+                COUNTER.increment();
+            });
+        }
+    });
+    assert_eq!(COUNTER.get(), 2);
+
+    /* and under contention */
+    static CONTENDED: Counter = Counter::new(0);
+
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..10_000 {
+                    CONTENDED.increment();
+                }
+            });
+        }
+    });
+    assert_eq!(CONTENDED.get(), THREADS as i32 * 10_000);
+}
+
+#[test]
+fn checked_initializations_run_twice_once_lock_runs_once() {
+
+    /* `if !INITIALIZED { CONFIG = ...; INITIALIZED = true; }`:
+       both threads check before any of them initializes */
+    static INITIALIZED: AtomicBool = AtomicBool::new(false);
+    let initializations = Counter::new(0);
+    let barrier = Barrier::new(2);
+
+    thread::scope(|scope| {
+        for _ in 0..2 {
+            scope.spawn(|| {
+                let initialized = INITIALIZED.load(Ordering::SeqCst);
+                barrier.wait();
+                if !initialized {
+                    initializations.increment();
+                    INITIALIZED.store(true, Ordering::SeqCst);
+                }
+            });
+        }
+    });
+    assert_eq!(initializations.get(), 2);
+
+    /* every thread tries to set its own configuration, one of them wins */
+    let barrier = Barrier::new(THREADS);
+    let results: Vec<(bool, &Config)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|workers| {
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    barrier.wait();
+                    let config = Config {
+                        name: format!("thread {}", workers),
+                        workers,
+                    };
+                    let initialized = global_state::init_config(config).is_ok();
+                    (initialized, global_state::config())
+                })
+            })
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+
+    assert_eq!(results.iter().filter(|(initialized, _)| *initialized).count(), 1);
+
+    /* all the threads read the configuration of the winner */
+    let winner = results.iter().find(|(initialized, _)| *initialized).unwrap().1;
+    assert!(results.iter().all(|(_, config)| config == &winner));
+    assert_eq!(winner.name, format!("thread {}", winner.workers));
+    assert!(CONFIG_READS.get() >= THREADS as i32);
+}
+
+#[test]
+fn split_map_updates_lose_entries_locked_updates_do_not() {
+
+    /* locking for the read and for the write separately is like a `static mut` map:
+       both threads copy the empty map, each one writes its copy back */
+    let unsynchronized = Mutex::new(HashMap::new());
+    let barrier = Barrier::new(2);
+
+    thread::scope(|scope| {
+        for name in ["first", "second"] {
+            let unsynchronized = &unsynchronized;
+            let barrier = &barrier;
+            scope.spawn(move || {
+                let mut copy = unsynchronized.lock().unwrap().clone();
+                barrier.wait();
+                copy.insert(name, 1);
+                *unsynchronized.lock().unwrap() = copy;
+            });
+        }
+    });
+    assert_eq!(unsynchronized.lock().unwrap().len(), 1);
+
+    /* the registry keeps the lock during the whole update */
+    thread::scope(|scope| {
+        for thread in 0..THREADS {
+            scope.spawn(move || {
+                global_state::register(&format!("registry thread {}", thread), thread as u32);
+                for _ in 0..1000 {
+                    global_state::update("registry total", |total| total.unwrap_or(0) + 1);
+                }
+            });
+        }
+    });
+
+    for thread in 0..THREADS {
+        assert_eq!(global_state::lookup(&format!("registry thread {}", thread)), Some(thread as u32));
+    }
+    assert_eq!(global_state::lookup("registry total"), Some(THREADS as u32 * 1000));
+    assert_eq!(global_state::lookup("never registered"), None);
+}
+
+#[test]
+fn shared_counters_mix_the_threads_thread_locals_do_not() {
+
+    /* one global for all the threads: every thread sees the calls of the other ones */
+    static SHARED: Counter = Counter::new(0);
+    let barrier = Barrier::new(THREADS);
+
+    let seen: Vec<(i32, u32)> = thread::scope(|scope| {
+        let handles: Vec<_> = (1..=THREADS)
+            .map(|calls| {
+                let barrier = &barrier;
+                scope.spawn(move || {
+                    for _ in 0..calls {
+                        SHARED.increment();
+                        global_state::record_call();
+                    }
+                    barrier.wait();
+                    (SHARED.get(), global_state::thread_calls())
+                })
+            })
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+
+    /* 1 + 2 + ... + 8 calls in total */
+    let total = (THREADS * (THREADS + 1) / 2) as i32;
+    assert!(seen.iter().all(|(shared, _)| *shared == total));
+
+    /* every thread only counted its own calls */
+    for (index, (_, own)) in seen.iter().enumerate() {
+        assert_eq!(*own as usize, index + 1);
+    }
+    assert_eq!(global_state::thread_calls(), 0);
+}
@@ -0,0 +1,262 @@
+/* const fn helpers working on string literals and byte slices,
+   so configuration keys are checked and hashed during the compilation;
+
+   possible on stable Rust:
+   - `str::len`, `str::as_bytes`, `str::is_empty`, indexing a byte slice,
+   - `while` loops over the bytes, `if`, `match`, integer arithmetic,
+     `checked_*` and `wrapping_*` methods,
+   - `panic!` and `assert!` with a literal message: during the evaluation
+     of a constant, they become compilation errors,
+   - `u64::from_str_radix` (no prefix, no separator accepted)
+
+   not possible on stable Rust:
+   - comparing strings with `==` and parsing them with `str::parse`,
+     they call trait methods (`PartialEq`, `FromStr`), trait methods are not const,
+   - slicing a string with a range (`&text[1..]`, `Index` trait),
+   - `for` loops (`Iterator` trait),
+   - formatting a panic message with values (`panic!("{}", value)`),
+   - allocating (`String`, `format!`) */
+
+/// Fails the compilation if the condition is false:
+///
+/// ```compile_fail,E0080
+/// constants::const_assert!(u8::MAX as u32 + 1 == 255, "u8 has 256 values");
+/// ```
+#[macro_export]
+macro_rules! const_assert {
+    ($condition:expr $(,)?) => {
+        const _: () = assert!($condition);
+    };
+    ($condition:expr, $message:literal $(,)?) => {
+        const _: () = assert!($condition, $message);
+    };
+}
+
+/// Compares the bytes of both strings, `==` cannot be called in a constant:
+///
+/// ```compile_fail,E0015
+/// const EQUAL: bool = "key" == "key";
+/// ```
+pub const fn equals(
+    first: &str,
+    second: &str,
+) -> bool
+{
+    let first = first.as_bytes();
+    let second = second.as_bytes();
+
+    if first.len() != second.len() {
+        return false;
+    }
+
+    let mut index = 0;
+    while index < first.len() {
+        if first[index] != second[index] {
+            return false;
+        }
+        index += 1;
+    }
+
+    true
+}
+
+/// Returns true if the length of the text is between min and max (included)
+pub const fn has_length(
+    text: &str,
+    min: usize,
+    max: usize,
+) -> bool
+{
+    text.len() >= min && text.len() <= max
+}
+
+/* FNV-1a: every byte is mixed into the hash with a xor, then a multiplication */
+
+const FNV_32_OFFSET: u32 = 0x811C_9DC5;
+const FNV_32_PRIME: u32 = 0x0100_0193;
+const FNV_64_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_64_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// FNV-1a hash of the bytes, 32 bits version
+pub const fn fnv1a_32(bytes: &[u8]) -> u32 {
+
+    let mut hash = FNV_32_OFFSET;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        hash ^= bytes[index] as u32;
+        hash = hash.wrapping_mul(FNV_32_PRIME);
+        index += 1;
+    }
+
+    hash
+}
+
+/// FNV-1a hash of the bytes, 64 bits version
+pub const fn fnv1a_64(bytes: &[u8]) -> u64 {
+
+    let mut hash = FNV_64_OFFSET;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(FNV_64_PRIME);
+        index += 1;
+    }
+
+    hash
+}
+
+/// Returns the value of the digit in the given radix (up to 16)
+const fn digit_value(
+    byte: u8,
+    radix: u64,
+) -> Option<u64>
+{
+    let value = match byte {
+        b'0'..=b'9' => byte - b'0',
+        b'a'..=b'f' => byte - b'a' + 10,
+        b'A'..=b'F' => byte - b'A' + 10,
+        _ => return None,
+    } as u64;
+
+    if value < radix {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Parses the digits, `_` separators are skipped (not first);
+/// returns None if the digits are empty, invalid, or overflow
+const fn parse_digits(
+    bytes: &[u8],
+    radix: u64,
+) -> Option<u64>
+{
+    if bytes.is_empty() || bytes[0] == b'_' {
+        return None;
+    }
+
+    let mut value: u64 = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+
+        if bytes[index] != b'_' {
+
+            let digit = match digit_value(bytes[index], radix) {
+                Some(digit) => digit,
+                None => return None,
+            };
+
+            /* `?` is not const, the overflows are checked by matches */
+            value = match value.checked_mul(radix) {
+                Some(value) => value,
+                None => return None,
+            };
+            value = match value.checked_add(digit) {
+                Some(value) => value,
+                None => return None,
+            };
+        }
+
+        index += 1;
+    }
+
+    Some(value)
+}
+
+/// Parses a decimal integer like `1_000_000`, `str::parse` cannot be called in a constant:
+///
+/// ```compile_fail,E0015
+/// const PARSED: u64 = "42".parse::<u64>().unwrap();
+/// ```
+pub const fn parse_decimal(text: &str) -> Option<u64> {
+    parse_digits(text.as_bytes(), 10)
+}
+
+/// Parses a hexadecimal integer like `0xFF_FF` (the `0x` prefix is optional)
+pub const fn parse_hex(text: &str) -> Option<u64> {
+
+    /* the prefix is skipped by splitting the bytes, slicing a str is not const */
+    let bytes = text.as_bytes();
+    let has_prefix = bytes.len() >= 2 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X');
+
+    if has_prefix {
+        parse_digits(bytes.split_at(2).1, 16)
+    } else {
+        parse_digits(bytes, 16)
+    }
+}
+
+/// Parses a decimal integer with an optional sign, like `-42`
+pub const fn parse_signed(text: &str) -> Option<i64> {
+
+    let bytes = text.as_bytes();
+    let (negative, digits) = match bytes {
+        [b'-', ..] => (true, bytes.split_at(1).1),
+        [b'+', ..] => (false, bytes.split_at(1).1),
+        _ => (false, bytes),
+    };
+
+    let value = match parse_digits(digits, 10) {
+        Some(value) => value,
+        None => return None,
+    };
+
+    /* i64::MIN has no positive counterpart */
+    if negative && value == i64::MAX as u64 + 1 {
+        return Some(i64::MIN);
+    }
+    if value > i64::MAX as u64 {
+        return None;
+    }
+
+    if negative {
+        Some(-(value as i64))
+    } else {
+        Some(value as i64)
+    }
+}
+
+/// Configuration key checked and hashed during the compilation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigKey {
+    pub name: &'static str,
+    pub hash: u64,
+}
+
+pub const MAX_KEY_LENGTH: usize = 64;
+
+impl ConfigKey {
+
+    /// Accepts lowercase letters, digits, `.` and `_`, from 1 to 64 characters;
+    /// an invalid key given to a constant does not compile
+    ///
+    /// ```compile_fail,E0080
+    /// use constants::const_str::ConfigKey;
+    ///
+    /// const KEY: ConfigKey = ConfigKey::new("Database.URL");
+    /// ```
+    pub const fn new(name: &'static str) -> ConfigKey {
+
+        assert!(has_length(name, 1, MAX_KEY_LENGTH), "a key has from 1 to 64 characters");
+
+        let bytes = name.as_bytes();
+        let mut index = 0;
+
+        while index < bytes.len() {
+            assert!(
+                matches!(bytes[index], b'a'..=b'z' | b'0'..=b'9' | b'.' | b'_'),
+                "a key only contains lowercase letters, digits, dots and underscores",
+            );
+            index += 1;
+        }
+
+        ConfigKey {
+            name,
+            hash: fnv1a_64(bytes),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+pub mod const_str;
+pub mod tables;
+pub mod units;
+
+use const_str::ConfigKey;
+use tables::{
+    PerfectMap,
+    BASE64_DECODE_TABLE,
+    POPCOUNT_TABLE,
+    SINE_TABLE,
+};
+use units::{
+    Length,
+    Mass,
+    Time,
+    Velocity,
+    STANDARD_GRAVITY,
+};
+
+/* constants can be declared to be part of the global scope */
+#[allow(unused)]
+const GLOBAL_CONSTANT: u8 = 0;
+
+/* const before fn means this function can be evaluated at compile-time */
+const fn get_constant() -> u8 {
+
+    /* this function can contain let declarations */
+    let value = 5;
+
+    value * 2
+}
+
+/* "while" and "loop" can be used into a const function;
+   "for" cannot, as it calls the Iterator trait methods
+   that are not const */
+const fn sum_until(limit: u32) -> u32 {
+
+    let mut sum = 0;
+    let mut index = 0;
+
+    while index <= limit {
+        sum += index;
+        index += 1;
+    }
+
+    sum
+}
+
+/* "if" and "match" can be used into a const function */
+const fn get_highest(
+    first: u32,
+    second: u32,
+) -> u32
+{
+    if first > second {
+        first
+    } else {
+        second
+    }
+}
+
+const fn get_parity(value: u32) -> &'static str {
+
+    match value % 2 {
+        0 => "even",
+        _ => "odd",
+    }
+}
+
+/* const generics: the length of the array is a parameter
+   known at compile-time, so one function handles every length */
+const fn get_array_sum<const N: usize>(array: [u32; N]) -> u32 {
+
+    let mut sum = 0;
+    let mut index = 0;
+
+    while index < N {
+        sum += array[index];
+        index += 1;
+    }
+
+    sum
+}
+
+const fn get_filled_array<const N: usize>(value: u8) -> [u8; N] {
+    [value; N]
+}
+
+/// Returns the CRC32 lookup table (IEEE polynomial, reversed),
+/// one entry per possible byte
+pub const fn get_crc32_table() -> [u32; 256] {
+
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+/* the whole table is computed during the compilation,
+   nothing is calculated when the program starts */
+pub const CRC32_TABLE: [u32; 256] = get_crc32_table();
+
+/// Returns the CRC32 checksum of the bytes
+pub const fn crc32(bytes: &[u8]) -> u32 {
+
+    let mut crc = 0xFFFF_FFFF;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let entry = ((crc ^ bytes[index] as u32) & 0xFF) as usize;
+        crc = (crc >> 8) ^ CRC32_TABLE[entry];
+        index += 1;
+    }
+
+    !crc
+}
+
+#[allow(unused)]
+pub fn run() {
+
+    /* this is a constant variable,
+       the type is mandatory */
+    const CONSTANT: u8 = 0;
+
+    /* a constant can be built from another constant */
+    const OTHER_CONSTANT: u8 = CONSTANT;
+
+    /* a constant be be built from an operation on constants and raw values */
+    const ANOTHER_CONSTANT: u8 = CONSTANT + OTHER_CONSTANT + 25;
+
+    /* a constant cannot be built from a variable */
+    let first_variable = 10;
+    //error: const RESULT: u8 = first_variable;
+
+    /* a constant cannot be set a second time */
+    const VALUE: u8 = 20;
+    //error: VALUE = 30;
+
+    /* constant can be built from a function
+       if this function result can be evaluated
+       at compile time */
+    const FROM_FUNCTION: u8 = get_constant();
+    println!("{}", FROM_FUNCTION); // 10
+
+    /* loops and conditions are evaluated at compile-time too */
+    const SUM: u32 = sum_until(10);
+    const HIGHEST: u32 = get_highest(SUM, 50);
+    const PARITY: &str = get_parity(SUM);
+    println!("{}", SUM); // 55
+    println!("{}", HIGHEST); // 55
+    println!("{}", PARITY); // odd
+
+    /* the generic length is deduced from the passed array */
+    const ARRAY_SUM: u32 = get_array_sum([1, 2, 3, 4]);
+    const FILLED_ARRAY: [u8; 3] = get_filled_array(7);
+    println!("{}", ARRAY_SUM); // 10
+    println!("{:?}", FILLED_ARRAY); // [7, 7, 7]
+
+    /* a lookup table and a checksum computed at compile-time */
+    const CHECKSUM: u32 = crc32(b"123456789");
+    println!("{:08x}", CRC32_TABLE[1]); // 77073096
+    println!("{:08x}", CHECKSUM); // cbf43926
+
+    /* quantities carry their dimensions as const generic parameters */
+    let distance = Length::kilometers(1.5);
+    let duration = Time::minutes(2.0);
+    let speed = distance / duration;
+    println!("{}", speed); // 12.5 m s^-1
+    println!("{:.2}", Mass::kilograms(70.0) * STANDARD_GRAVITY); // 686.47 kg m s^-2
+    println!("{}", Velocity::kilometers_per_hour(90.0) * Time::hours(2.0)); // 180000 m
+    println!("{:?}", (speed / duration).dimensions()); // (0, 1, -2)
+    //error: let nonsense = distance + duration;
+
+    /* strings are checked, hashed and parsed during the compilation */
+    const TIMEOUT_KEY: ConfigKey = ConfigKey::new("server.timeout");
+    const TIMEOUT_HASH: u64 = TIMEOUT_KEY.hash;
+    const TIMEOUT: u64 = const_str::parse_decimal("30_000").unwrap();
+    const MASK: u64 = const_str::parse_hex("0xFF_00").unwrap();
+    const_assert!(TIMEOUT.is_multiple_of(1000), "the timeout is a whole amount of seconds");
+    println!("{:016x}", TIMEOUT_HASH); // b00f23c36842638f
+    println!("{}", TIMEOUT); // 30000
+    println!("{}", MASK); // 65280
+
+    /* a runtime key is hashed at runtime, the constant hash can be a pattern */
+    let key = String::from("server.timeout");
+    match const_str::fnv1a_64(key.as_bytes()) {
+        TIMEOUT_HASH => println!("timeout"), // timeout
+        _ => println!("unknown"),
+    }
+    //error: const INVALID: ConfigKey = ConfigKey::new("Server.Timeout");
+
+    /* whole lookup tables and a perfect hash map are built during the compilation */
+    const STATUS_CODES: PerfectMap<u16, 4, 8> = PerfectMap::new([
+        ("ok", 200),
+        ("created", 201),
+        ("not found", 404),
+        ("teapot", 418),
+    ]);
+    const TEAPOT: u16 = *STATUS_CODES.get("teapot").unwrap();
+    println!("{:.4}", SINE_TABLE[32]); // 0.7071
+    println!("{}", POPCOUNT_TABLE[0b1011_0110]); // 5
+    println!("{}", BASE64_DECODE_TABLE[b'z' as usize]); // 51
+    println!("{}", TEAPOT); // 418
+    println!("{:?}", STATUS_CODES.get("gone")); // None
+}
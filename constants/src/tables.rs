@@ -0,0 +1,284 @@
+/* lookup tables computed entirely during the compilation,
+   like the CRC32 table: the program reads them, it never computes them;
+
+   every table is filled by a `while` loop into a `const fn`,
+   the float arithmetic is const too, but not `f64::sin`,
+   so the sine is computed by its Taylor series */
+
+use std::f64::consts::PI;
+
+use crate::const_str::{
+    equals,
+    fnv1a_64,
+};
+
+pub const SINE_TABLE_SIZE: usize = 256;
+
+/// Sine of an angle from -π to π, Taylor series up to x^29
+const fn taylor_sine(angle: f64) -> f64 {
+
+    let mut term = angle;
+    let mut sum = angle;
+    let mut power = 1;
+
+    /* every term is the previous one multiplied by -x² / ((2n) (2n + 1)) */
+    while power < 15 {
+        let divisor = ((2 * power) * (2 * power + 1)) as f64;
+        term = -term * angle * angle / divisor;
+        sum += term;
+        power += 1;
+    }
+
+    sum
+}
+
+/// Returns the sines of the angles 2π i / SINE_TABLE_SIZE
+pub const fn get_sine_table() -> [f64; SINE_TABLE_SIZE] {
+
+    let mut table = [0.0; SINE_TABLE_SIZE];
+    let mut index = 0;
+
+    while index < SINE_TABLE_SIZE {
+
+        /* the series converges faster close to 0, the angles go from -π to π */
+        let mut angle = 2.0 * PI * index as f64 / SINE_TABLE_SIZE as f64;
+        if angle > PI {
+            angle -= 2.0 * PI;
+        }
+
+        table[index] = taylor_sine(angle);
+        index += 1;
+    }
+
+    table
+}
+
+pub const SINE_TABLE: [f64; SINE_TABLE_SIZE] = get_sine_table();
+
+/// Sine of any angle, interpolated between the two closest entries of the table
+pub fn table_sine(angle: f64) -> f64 {
+
+    let position = angle.rem_euclid(2.0 * PI) / (2.0 * PI) * SINE_TABLE_SIZE as f64;
+    let index = position as usize % SINE_TABLE_SIZE;
+    let next = (index + 1) % SINE_TABLE_SIZE;
+    let fraction = position.fract();
+
+    SINE_TABLE[index] + (SINE_TABLE[next] - SINE_TABLE[index]) * fraction
+}
+
+/// Returns the amount of bits set of every byte
+pub const fn get_popcount_table() -> [u8; 256] {
+
+    let mut table = [0; 256];
+    let mut index = 1;
+
+    /* the bits of i are the bits of i / 2 (already computed) and its lowest bit */
+    while index < 256 {
+        table[index] = table[index / 2] + (index & 1) as u8;
+        index += 1;
+    }
+
+    table
+}
+
+pub const POPCOUNT_TABLE: [u8; 256] = get_popcount_table();
+
+/// Amount of bits set, one lookup per byte
+pub const fn popcount(value: u32) -> u32 {
+
+    let bytes = value.to_le_bytes();
+
+    POPCOUNT_TABLE[bytes[0] as usize] as u32
+        + POPCOUNT_TABLE[bytes[1] as usize] as u32
+        + POPCOUNT_TABLE[bytes[2] as usize] as u32
+        + POPCOUNT_TABLE[bytes[3] as usize] as u32
+}
+
+pub const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Entry of the bytes that are not in the alphabet
+pub const BASE64_INVALID: u8 = 0xFF;
+
+/// Returns the value of every byte of the base64 alphabet, the inverse of the alphabet
+pub const fn get_base64_decode_table() -> [u8; 256] {
+
+    let mut table = [BASE64_INVALID; 256];
+    let mut index = 0;
+
+    while index < BASE64_ALPHABET.len() {
+        table[BASE64_ALPHABET[index] as usize] = index as u8;
+        index += 1;
+    }
+
+    table
+}
+
+pub const BASE64_DECODE_TABLE: [u8; 256] = get_base64_decode_table();
+
+/// Decodes padded base64, returns None if a character is invalid
+pub fn base64_decode(text: &str) -> Option<Vec<u8>> {
+
+    let bytes = text.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(bytes.len() / 4 * 3);
+
+    for (index, chunk) in bytes.chunks(4).enumerate() {
+
+        /* the padding is only allowed at the end */
+        let last = index == bytes.len() / 4 - 1;
+        let padding = chunk.iter().rev().take_while(|byte| **byte == b'=').count();
+        if padding > 2 || (padding > 0 && !last) {
+            return None;
+        }
+
+        let mut group: u32 = 0;
+        for byte in &chunk[..4 - padding] {
+            let value = BASE64_DECODE_TABLE[*byte as usize];
+            if value == BASE64_INVALID {
+                return None;
+            }
+            group = group << 6 | value as u32;
+        }
+        group <<= 6 * padding;
+
+        decoded.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+
+    Some(decoded)
+}
+
+/* perfect hash map: the keys are known during the compilation,
+   so a seed is searched for which every key has its own slot;
+   a lookup hashes the key once and compares one key only */
+
+/// Spreads the bits of the hash (splitmix64 finalizer),
+/// so every seed gives unrelated slots
+const fn mix(mut value: u64) -> u64 {
+
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+const fn slot(
+    key: &str,
+    seed: u64,
+    slots: usize,
+) -> usize
+{
+    (mix(fnv1a_64(key.as_bytes()) ^ seed) % slots as u64) as usize
+}
+
+/// Seeds tried before giving up
+pub const MAX_SEEDS: u64 = 100_000;
+
+/// Map of N entries with static keys, spread into S slots (S >= N)
+#[derive(Debug)]
+pub struct PerfectMap<V, const N: usize, const S: usize> {
+    entries: [(&'static str, V); N],
+
+    /* index of the entry of every slot, N for an empty slot */
+    slots: [usize; S],
+
+    seed: u64,
+}
+
+impl<V, const N: usize, const S: usize> PerfectMap<V, N, S> {
+
+    /// Searches a seed without collision; given to a constant,
+    /// a duplicated key or too few slots do not compile
+    ///
+    /// ```compile_fail,E0080
+    /// use constants::tables::PerfectMap;
+    ///
+    /// const MAP: PerfectMap<u8, 2, 4> = PerfectMap::new([("key", 1), ("key", 2)]);
+    /// ```
+    pub const fn new(entries: [(&'static str, V); N]) -> PerfectMap<V, N, S> {
+
+        assert!(S >= N, "there are less slots than entries");
+
+        let mut first = 0;
+        while first < N {
+            let mut second = first + 1;
+            while second < N {
+                assert!(!equals(entries[first].0, entries[second].0), "a key is duplicated");
+                second += 1;
+            }
+            first += 1;
+        }
+
+        let mut seed = 0;
+        while seed < MAX_SEEDS {
+
+            let mut slots = [N; S];
+            let mut index = 0;
+
+            while index < N {
+                let slot = slot(entries[index].0, seed, S);
+                if slots[slot] != N {
+                    break;
+                }
+                slots[slot] = index;
+                index += 1;
+            }
+
+            if index == N {
+                return PerfectMap {
+                    entries,
+                    slots,
+                    seed,
+                };
+            }
+
+            seed += 1;
+        }
+
+        panic!("no seed without collision, add slots");
+    }
+
+    /// Returns the value of the key; const, so it can be used by other constants
+    pub const fn get(
+        &self,
+        key: &str,
+    ) -> Option<&V>
+    {
+        if N == 0 {
+            return None;
+        }
+
+        let index = self.slots[slot(key, self.seed, S)];
+        if index == N || !equals(self.entries[index].0, key) {
+            return None;
+        }
+
+        Some(&self.entries[index].1)
+    }
+
+    pub const fn contains_key(
+        &self,
+        key: &str,
+    ) -> bool
+    {
+        self.get(key).is_some()
+    }
+
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Seed found during the construction
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &V)> {
+        self.entries.iter().map(|(key, value)| (*key, value))
+    }
+}
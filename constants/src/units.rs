@@ -0,0 +1,319 @@
+/* dimensional analysis at compile time: a quantity has the exponents
+   of its mass (M), length (L) and time (T) as const generic parameters,
+   the value is always stored in SI units (kilograms, meters, seconds);
+
+   quantities of different dimensions are different types,
+   so they cannot be added, compared or given instead of each other
+
+   the dimensions of a product should be the sums of the exponents,
+   but `Quantity<{ M1 + M2 }, ...>` requires the unstable `generic_const_exprs`
+   feature; on stable Rust, every allowed product and quotient is declared
+   by the `relation!` macro below */
+
+use std::fmt;
+use std::ops::{
+    Add,
+    Div,
+    Mul,
+    Neg,
+    Sub,
+};
+
+/// Value in SI units with the exponents of its dimensions
+///
+/// Quantities of different dimensions cannot be added:
+///
+/// ```compile_fail,E0308
+/// use constants::units::{Length, Time};
+///
+/// let nonsense = Length::meters(100.0) + Time::seconds(9.58);
+/// ```
+///
+/// The result of a division has the derived dimensions:
+///
+/// ```compile_fail,E0308
+/// use constants::units::{Length, Time};
+///
+/// let speed: Length = Length::meters(100.0) / Time::seconds(9.58);
+/// ```
+///
+/// Only the declared relations can be multiplied:
+///
+/// ```compile_fail,E0277
+/// use constants::units::{Force, Velocity};
+///
+/// let unknown = Force::newtons(1.0) * Velocity::meters_per_second(1.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Quantity<const M: i8, const L: i8, const T: i8> {
+    value: f64,
+}
+
+pub type Scalar = Quantity<0, 0, 0>;
+pub type Mass = Quantity<1, 0, 0>;
+pub type Length = Quantity<0, 1, 0>;
+pub type Time = Quantity<0, 0, 1>;
+pub type Area = Quantity<0, 2, 0>;
+pub type Frequency = Quantity<0, 0, -1>;
+pub type Velocity = Quantity<0, 1, -1>;
+/// Energy per kilogram, like the v^2 of the kinetic energy m v^2 / 2
+pub type SquaredVelocity = Quantity<0, 2, -2>;
+pub type Acceleration = Quantity<0, 1, -2>;
+pub type Momentum = Quantity<1, 1, -1>;
+pub type Force = Quantity<1, 1, -2>;
+pub type Energy = Quantity<1, 2, -2>;
+pub type Power = Quantity<1, 2, -3>;
+
+impl<const M: i8, const L: i8, const T: i8> Quantity<M, L, T> {
+
+    /// Creates the quantity from its value in SI units
+    pub const fn new(value: f64) -> Quantity<M, L, T> {
+        Quantity {
+            value,
+        }
+    }
+
+    /// Returns the value in SI units
+    pub const fn value(self) -> f64 {
+        self.value
+    }
+
+    /* the exponents are constants of the type, readable at runtime */
+    pub const fn dimensions(self) -> (i8, i8, i8) {
+        (M, L, T)
+    }
+}
+
+/* unit constructors; they are const, so they can define constants */
+
+impl Mass {
+
+    pub const fn kilograms(value: f64) -> Mass {
+        Mass::new(value)
+    }
+
+    pub const fn grams(value: f64) -> Mass {
+        Mass::new(value / 1000.0)
+    }
+}
+
+impl Length {
+
+    pub const fn meters(value: f64) -> Length {
+        Length::new(value)
+    }
+
+    pub const fn kilometers(value: f64) -> Length {
+        Length::new(value * 1000.0)
+    }
+}
+
+impl Time {
+
+    pub const fn seconds(value: f64) -> Time {
+        Time::new(value)
+    }
+
+    pub const fn minutes(value: f64) -> Time {
+        Time::new(value * 60.0)
+    }
+
+    pub const fn hours(value: f64) -> Time {
+        Time::new(value * 3600.0)
+    }
+}
+
+impl Velocity {
+
+    pub const fn meters_per_second(value: f64) -> Velocity {
+        Velocity::new(value)
+    }
+
+    pub const fn kilometers_per_hour(value: f64) -> Velocity {
+        Velocity::new(value / 3.6)
+    }
+}
+
+impl Acceleration {
+
+    pub const fn meters_per_second_squared(value: f64) -> Acceleration {
+        Acceleration::new(value)
+    }
+}
+
+impl Force {
+
+    pub const fn newtons(value: f64) -> Force {
+        Force::new(value)
+    }
+}
+
+impl Energy {
+
+    pub const fn joules(value: f64) -> Energy {
+        Energy::new(value)
+    }
+}
+
+impl Power {
+
+    pub const fn watts(value: f64) -> Power {
+        Power::new(value)
+    }
+}
+
+/// Speed of light in vacuum, exact by definition of the meter
+pub const SPEED_OF_LIGHT: Velocity = Velocity::meters_per_second(299_792_458.0);
+
+/// Standard acceleration of gravity on Earth
+pub const STANDARD_GRAVITY: Acceleration = Acceleration::meters_per_second_squared(9.806_65);
+
+/* a constant built from other constants, evaluated during the compilation */
+pub const LIGHT_YEAR: Length = Length::meters(SPEED_OF_LIGHT.value() * 365.25 * 86_400.0);
+
+/* same dimensions only: the generic parameters of both sides are the same */
+
+impl<const M: i8, const L: i8, const T: i8> Add for Quantity<M, L, T> {
+    type Output = Quantity<M, L, T>;
+
+    fn add(
+        self,
+        other: Quantity<M, L, T>,
+    ) -> Quantity<M, L, T>
+    {
+        Quantity::new(self.value + other.value)
+    }
+}
+
+impl<const M: i8, const L: i8, const T: i8> Sub for Quantity<M, L, T> {
+    type Output = Quantity<M, L, T>;
+
+    fn sub(
+        self,
+        other: Quantity<M, L, T>,
+    ) -> Quantity<M, L, T>
+    {
+        Quantity::new(self.value - other.value)
+    }
+}
+
+impl<const M: i8, const L: i8, const T: i8> Neg for Quantity<M, L, T> {
+    type Output = Quantity<M, L, T>;
+
+    fn neg(self) -> Quantity<M, L, T> {
+        Quantity::new(-self.value)
+    }
+}
+
+/* a number without dimension scales any quantity */
+
+impl<const M: i8, const L: i8, const T: i8> Mul<f64> for Quantity<M, L, T> {
+    type Output = Quantity<M, L, T>;
+
+    fn mul(
+        self,
+        factor: f64,
+    ) -> Quantity<M, L, T>
+    {
+        Quantity::new(self.value * factor)
+    }
+}
+
+impl<const M: i8, const L: i8, const T: i8> Mul<Quantity<M, L, T>> for f64 {
+    type Output = Quantity<M, L, T>;
+
+    fn mul(
+        self,
+        quantity: Quantity<M, L, T>,
+    ) -> Quantity<M, L, T>
+    {
+        Quantity::new(self * quantity.value)
+    }
+}
+
+impl<const M: i8, const L: i8, const T: i8> Div<f64> for Quantity<M, L, T> {
+    type Output = Quantity<M, L, T>;
+
+    fn div(
+        self,
+        divisor: f64,
+    ) -> Quantity<M, L, T>
+    {
+        Quantity::new(self.value / divisor)
+    }
+}
+
+/* `relation!(A * B = C)` declares A * B, B * A, C / A and C / B,
+   `relation!(A ^ 2 = C)` declares A * A and C / A */
+macro_rules! relation {
+    (@multiply $first:ident, $second:ident, $result:ident) => {
+        impl Mul<$second> for $first {
+            type Output = $result;
+
+            fn mul(
+                self,
+                other: $second,
+            ) -> $result
+            {
+                $result::new(self.value * other.value)
+            }
+        }
+    };
+    (@divide $dividend:ident, $divisor:ident, $result:ident) => {
+        impl Div<$divisor> for $dividend {
+            type Output = $result;
+
+            fn div(
+                self,
+                other: $divisor,
+            ) -> $result
+            {
+                $result::new(self.value / other.value)
+            }
+        }
+    };
+    ($first:ident ^ 2 = $result:ident) => {
+        relation!(@multiply $first, $first, $result);
+        relation!(@divide $result, $first, $first);
+    };
+    ($first:ident * $second:ident = $result:ident) => {
+        relation!(@multiply $first, $second, $result);
+        relation!(@multiply $second, $first, $result);
+        relation!(@divide $result, $first, $second);
+        relation!(@divide $result, $second, $first);
+    };
+}
+
+relation!(Length ^ 2 = Area);
+relation!(Frequency * Time = Scalar);
+relation!(Velocity * Time = Length);
+relation!(Acceleration * Time = Velocity);
+relation!(Velocity ^ 2 = SquaredVelocity);
+relation!(Mass * Velocity = Momentum);
+relation!(Mass * SquaredVelocity = Energy);
+relation!(Mass * Acceleration = Force);
+relation!(Force * Length = Energy);
+relation!(Power * Time = Energy);
+
+/* the value followed by the SI units and their exponents, like 9.8 m s^-2 */
+impl<const M: i8, const L: i8, const T: i8> fmt::Display for Quantity<M, L, T> {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        /* the precision given to the formatter applies to the value */
+        fmt::Display::fmt(&self.value, formatter)?;
+
+        for (unit, exponent) in [("kg", M), ("m", L), ("s", T)] {
+            match exponent {
+                0 => {}
+                1 => write!(formatter, " {}", unit)?,
+                _ => write!(formatter, " {}^{}", unit, exponent)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use constants::const_assert;
+use constants::const_str::{
+    equals,
+    fnv1a_32,
+    fnv1a_64,
+    has_length,
+    parse_decimal,
+    parse_hex,
+    parse_signed,
+    ConfigKey,
+    MAX_KEY_LENGTH,
+};
+use constants::crc32;
+
+/* everything below is computed during the compilation */
+const DATABASE_URL: ConfigKey = ConfigKey::new("database.url");
+const CRC_OF_KEY: u32 = crc32(DATABASE_URL.name.as_bytes());
+const MILLION: u64 = parse_decimal("1_000_000").unwrap();
+const NEGATIVE: i64 = parse_signed("-273").unwrap();
+const COLOR: u64 = parse_hex("0xC0FFEE").unwrap();
+const STD_HEX: u64 = match u64::from_str_radix("C0FFEE", 16) {
+    Ok(value) => value,
+    Err(_) => panic!("invalid hexadecimal"),
+};
+
+const_assert!(MILLION == 1_000_000);
+const_assert!(COLOR == STD_HEX, "both parsers agree");
+const_assert!(has_length(DATABASE_URL.name, 1, MAX_KEY_LENGTH));
+const_assert!(!equals(DATABASE_URL.name, "database.user"));
+
+#[test]
+fn fnv_hashes_match_the_reference_values() {
+
+    assert_eq!(fnv1a_32(b""), 0x811C_9DC5);
+    assert_eq!(fnv1a_32(b"a"), 0xE40C_292C);
+    assert_eq!(fnv1a_32(b"foobar"), 0xBF9C_F968);
+
+    assert_eq!(fnv1a_64(b""), 0xCBF2_9CE4_8422_2325);
+    assert_eq!(fnv1a_64(b"a"), 0xAF63_DC4C_8601_EC8C);
+    assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_F739_67E8);
+}
+
+#[test]
+fn compile_time_keys_match_runtime_hashes() {
+
+    let runtime_name = ["database", "url"].join(".");
+
+    assert_eq!(DATABASE_URL.hash, fnv1a_64(runtime_name.as_bytes()));
+    assert_eq!(CRC_OF_KEY, crc32(runtime_name.as_bytes()));
+    assert_eq!(ConfigKey::new("a").name, "a");
+}
+
+#[test]
+#[should_panic(expected = "lowercase letters")]
+fn invalid_keys_panic_at_runtime() {
+
+    let name: &'static str = Box::leak(String::from("Database.URL").into_boxed_str());
+    ConfigKey::new(name);
+}
+
+#[test]
+#[should_panic(expected = "from 1 to 64 characters")]
+fn empty_keys_panic_at_runtime() {
+    ConfigKey::new("");
+}
+
+#[test]
+fn decimal_parsing_matches_str_parse() {
+
+    for value in [0, 1, 9, 10, 42, 1_000_000, u32::MAX as u64, u64::MAX] {
+        assert_eq!(parse_decimal(&value.to_string()), Some(value));
+    }
+
+    assert_eq!(MILLION, 1_000_000);
+    assert_eq!(parse_decimal(""), None);
+    assert_eq!(parse_decimal("_1"), None);
+    assert_eq!(parse_decimal("12a"), None);
+    assert_eq!(parse_decimal("-1"), None);
+    assert_eq!(parse_decimal("18446744073709551616"), None);
+}
+
+#[test]
+fn signed_parsing_matches_str_parse() {
+
+    for text in ["0", "-0", "+7", "-273", "9223372036854775807", "-9223372036854775808"] {
+        assert_eq!(parse_signed(text), text.parse::<i64>().ok(), "{}", text);
+    }
+
+    assert_eq!(NEGATIVE, -273);
+    assert_eq!(parse_signed("9223372036854775808"), None);
+    assert_eq!(parse_signed("-"), None);
+}
+
+#[test]
+fn hex_parsing_matches_from_str_radix() {
+
+    for value in [0, 0xA, 0xFF, 0xC0FFEE, u64::MAX] {
+        assert_eq!(parse_hex(&format!("{:x}", value)), Some(value));
+        assert_eq!(parse_hex(&format!("{:#X}", value)), Some(value));
+    }
+
+    assert_eq!(COLOR, 0xC0FFEE);
+    assert_eq!(parse_hex("0xFF_FF"), Some(0xFFFF));
+    assert_eq!(parse_hex("0x"), None);
+    assert_eq!(parse_hex("0xG"), None);
+    assert_eq!(parse_hex("1_0000_0000_0000_0000"), None);
+}
+
+#[test]
+fn strings_are_compared_byte_by_byte() {
+
+    assert!(equals("key", "key"));
+    assert!(!equals("key", "keys"));
+    assert!(!equals("key", "kex"));
+    assert!(equals("", ""));
+
+    assert!(has_length("abc", 3, 3));
+    assert!(!has_length("abc", 4, 10));
+}
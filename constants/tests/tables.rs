@@ -0,0 +1,138 @@
+use std::f64::consts::PI;
+
+use constants::tables::{
+    base64_decode,
+    popcount,
+    table_sine,
+    PerfectMap,
+    BASE64_ALPHABET,
+    BASE64_DECODE_TABLE,
+    BASE64_INVALID,
+    POPCOUNT_TABLE,
+    SINE_TABLE,
+    SINE_TABLE_SIZE,
+};
+
+const KEYWORDS: [(&str, u8); 12] = [
+    ("as", 0),
+    ("break", 1),
+    ("const", 2),
+    ("continue", 3),
+    ("crate", 4),
+    ("else", 5),
+    ("enum", 6),
+    ("extern", 7),
+    ("false", 8),
+    ("fn", 9),
+    ("for", 10),
+    ("if", 11),
+];
+
+/* the map is a static, its seed was searched during the compilation */
+static KEYWORD_MAP: PerfectMap<u8, 12, 16> = PerfectMap::new(KEYWORDS);
+
+/* a lookup into a constant map is a constant too */
+const KEYWORD_MAP_CONST: PerfectMap<u8, 12, 16> = PerfectMap::new(KEYWORDS);
+const FOR: Option<&u8> = KEYWORD_MAP_CONST.get("for");
+
+#[test]
+fn sine_table_matches_the_runtime_sine() {
+
+    for (index, value) in SINE_TABLE.iter().enumerate() {
+        let angle = 2.0 * PI * index as f64 / SINE_TABLE_SIZE as f64;
+        assert!((value - angle.sin()).abs() < 1e-12, "sin({}) = {} != {}", angle, value, angle.sin());
+    }
+
+    /* the interpolation between entries stays close */
+    for step in -1000..1000 {
+        let angle = step as f64 / 100.0;
+        assert!((table_sine(angle) - angle.sin()).abs() < 1e-3, "{}", angle);
+    }
+}
+
+#[test]
+fn popcount_table_matches_count_ones() {
+
+    for byte in 0..=u8::MAX {
+        assert_eq!(POPCOUNT_TABLE[byte as usize] as u32, byte.count_ones());
+    }
+
+    for value in [0, 1, 0xFF, 0xF0F0_F0F0, 0x8000_0001, 123_456_789, u32::MAX] {
+        assert_eq!(popcount(value), value.count_ones());
+    }
+}
+
+#[test]
+fn base64_table_is_the_inverse_of_the_alphabet() {
+
+    for byte in 0..=u8::MAX {
+        let expected = BASE64_ALPHABET
+            .iter()
+            .position(|character| *character == byte)
+            .map_or(BASE64_INVALID, |position| position as u8);
+        assert_eq!(BASE64_DECODE_TABLE[byte as usize], expected);
+    }
+}
+
+#[test]
+fn base64_decodes_the_reference_vectors() {
+
+    let vectors = [
+        ("", ""),
+        ("Zg==", "f"),
+        ("Zm8=", "fo"),
+        ("Zm9v", "foo"),
+        ("Zm9vYg==", "foob"),
+        ("Zm9vYmE=", "fooba"),
+        ("Zm9vYmFy", "foobar"),
+    ];
+    for (encoded, decoded) in vectors {
+        assert_eq!(base64_decode(encoded), Some(decoded.as_bytes().to_vec()), "{}", encoded);
+    }
+
+    assert_eq!(base64_decode("Zm9"), None);
+    assert_eq!(base64_decode("Zm9!"), None);
+    assert_eq!(base64_decode("Zg==Zm9v"), None);
+    assert_eq!(base64_decode("Z==="), None);
+}
+
+#[test]
+fn perfect_map_finds_every_key() {
+
+    assert_eq!(KEYWORD_MAP.len(), 12);
+    for (key, value) in KEYWORDS {
+        assert_eq!(KEYWORD_MAP.get(key), Some(&value), "{}", key);
+    }
+
+    assert_eq!(FOR, Some(&10));
+    assert_eq!(KEYWORD_MAP.iter().count(), 12);
+    assert_eq!(KEYWORD_MAP_CONST.seed(), KEYWORD_MAP.seed());
+}
+
+#[test]
+fn perfect_map_rejects_other_keys() {
+
+    for key in ["", "a", "While", "ifs", "fo", "impl", "let"] {
+        assert_eq!(KEYWORD_MAP.get(key), None, "{}", key);
+        assert!(!KEYWORD_MAP.contains_key(key));
+    }
+
+    let empty: PerfectMap<u8, 0, 0> = PerfectMap::new([]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.get("as"), None);
+}
+
+#[test]
+fn perfect_map_holds_values_without_copy() {
+
+    let map: PerfectMap<Vec<u8>, 2, 2> = PerfectMap::new([("first", vec![1]), ("second", vec![2, 2])]);
+
+    assert_eq!(map.get("second"), Some(&vec![2, 2]));
+    assert_eq!(map.get("third"), None);
+}
+
+#[test]
+#[should_panic(expected = "duplicated")]
+fn duplicated_keys_panic_at_runtime() {
+    let _: PerfectMap<u8, 2, 4> = PerfectMap::new([("key", 1), ("key", 2)]);
+}
@@ -0,0 +1,104 @@
+use constants::units::{
+    Acceleration,
+    Area,
+    Energy,
+    Force,
+    Frequency,
+    Length,
+    Mass,
+    Power,
+    Quantity,
+    Scalar,
+    Time,
+    Velocity,
+    LIGHT_YEAR,
+    SPEED_OF_LIGHT,
+    STANDARD_GRAVITY,
+};
+
+/* the constants are built during the compilation */
+const MARATHON: Length = Length::kilometers(42.195);
+const ONE_GRAM: Mass = Mass::grams(1.0);
+
+fn assert_close<const M: i8, const L: i8, const T: i8>(
+    actual: Quantity<M, L, T>,
+    expected: f64,
+) {
+    assert!((actual.value() - expected).abs() < 1e-9 * expected.abs().max(1.0), "{} != {}", actual, expected);
+}
+
+#[test]
+fn units_are_converted_to_si_values() {
+
+    assert_eq!(MARATHON.value(), 42_195.0);
+    assert_eq!(ONE_GRAM.value(), 0.001);
+    assert_eq!(Time::hours(1.0), Time::minutes(60.0));
+    assert_close(Velocity::kilometers_per_hour(36.0), 10.0);
+    assert_eq!(SPEED_OF_LIGHT.value(), 299_792_458.0);
+    assert_close(LIGHT_YEAR, 9_460_730_472_580_800.0);
+}
+
+#[test]
+fn same_dimensions_are_added_and_compared() {
+
+    let total = Length::meters(400.0) + Length::kilometers(1.0) - Length::meters(100.0);
+    assert_eq!(total, Length::meters(1300.0));
+    assert!(Length::kilometers(1.0) > Length::meters(999.0));
+    assert_eq!(-total, Length::meters(-1300.0));
+    assert_eq!(total * 2.0, 2.0 * total);
+    assert_eq!(total / 4.0, Length::meters(325.0));
+}
+
+#[test]
+fn products_and_quotients_have_derived_dimensions() {
+
+    let speed: Velocity = MARATHON / Time::hours(2.0);
+    assert_close(speed, 5.860_416_666_666_667);
+
+    let area: Area = Length::meters(3.0) * Length::meters(4.0);
+    assert_eq!(area.dimensions(), (0, 2, 0));
+    assert_eq!(area / Length::meters(4.0), Length::meters(3.0));
+
+    let weight: Force = Mass::kilograms(2.0) * STANDARD_GRAVITY;
+    assert_close(weight, 19.6133);
+    let acceleration: Acceleration = weight / Mass::kilograms(2.0);
+    assert_close(acceleration, STANDARD_GRAVITY.value());
+
+    let work: Energy = weight * Length::meters(10.0);
+    let power: Power = work / Time::seconds(4.0);
+    assert_close(power, 49.033_25);
+    assert_close(power * Time::seconds(4.0), work.value());
+
+    let frequency: Frequency = Scalar::new(50.0) / Time::seconds(1.0);
+    assert_eq!(frequency * Time::seconds(2.0), Scalar::new(100.0));
+}
+
+#[test]
+fn free_fall_combines_the_relations() {
+
+    /* v = g t, d = v t / 2, E = m v v / 2 */
+    let time = Time::seconds(3.0);
+    let speed = STANDARD_GRAVITY * time;
+    let distance = speed * time / 2.0;
+    let momentum = Mass::kilograms(1.0) * speed;
+    let energy: Energy = Mass::kilograms(2.0) * (speed * speed) / 2.0;
+
+    assert_close(speed, 29.419_95);
+    assert_close(distance, 44.129_925);
+    assert_eq!(momentum.dimensions(), (1, 1, -1));
+    assert_close(momentum / Mass::kilograms(1.0), speed.value());
+
+    /* the same energy as the work of the weight along the fall, E = m g d */
+    assert_close(energy, 865.533_458_002_5);
+    assert_close(energy, (Mass::kilograms(2.0) * STANDARD_GRAVITY * distance).value());
+    assert_close(energy / Mass::kilograms(2.0) / speed, speed.value() / 2.0);
+}
+
+#[test]
+fn display_shows_the_si_units() {
+
+    assert_eq!(Mass::kilograms(2.0).to_string(), "2 kg");
+    assert_eq!(format!("{:.1}", STANDARD_GRAVITY), "9.8 m s^-2");
+    assert_eq!(Energy::joules(5.0).to_string(), "5 kg m^2 s^-2");
+    assert_eq!(Scalar::new(0.5).to_string(), "0.5");
+}
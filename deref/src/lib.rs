@@ -0,0 +1,150 @@
+pub mod my_box;
+
+use std::fmt::Display;
+use std::ops::{
+    Deref,
+    DerefMut,
+};
+
+use my_box::MyBox;
+
+pub struct CustomBox<T> {
+    param: T,
+}
+
+impl<T> CustomBox<T> {
+
+    pub fn new(param: T) -> CustomBox<T> {
+        CustomBox {
+            param: param, 
+        }
+    }
+}
+
+/* the function "deref" defines what happens when
+   the type is de-referenced using *,
+   it is implemented by the std::ops::Deref trait
+   and must be implemented by the structure
+   that needs it */
+impl<T> Deref for CustomBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.param
+    }
+}
+
+#[allow(unused)]
+pub struct OtherCustomBox<T> {
+    param: T,
+}
+
+impl<T> OtherCustomBox<T> {
+
+    pub fn new(param: T) -> OtherCustomBox<T> {
+        OtherCustomBox {
+            param: param,
+        }
+    }
+}
+
+/// Implements `DerefMut`: a mutable reference to the box coerces to a mutable
+/// reference to its value, a shared reference never coerces to a mutable one:
+///
+/// ```compile_fail,E0308
+/// use deref::ThirdCustomBox;
+///
+/// fn increment(digit: &mut u8) {
+///     *digit += 1;
+/// }
+///
+/// let mut value = ThirdCustomBox::new(1);
+/// increment(&value);
+/// ```
+pub struct ThirdCustomBox<T> {
+    param: T,
+}
+
+impl<T> ThirdCustomBox<T> {
+
+    pub fn new(param: T) -> ThirdCustomBox<T> {
+        ThirdCustomBox {
+            param: param,
+        }
+    }
+}
+
+/* if a type implements DerefMut, it must implements Deref too */
+
+impl<T> Deref for ThirdCustomBox<T> {
+    type Target = T;
+
+    /* this is the required signature of the Deref object deref */
+    fn deref(&self) -> &T {
+        &self.param
+    }
+}
+
+impl<T> DerefMut for ThirdCustomBox<T> {
+
+    /* this is the required signature of the DerefMut object deref_mut */
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.param
+    }
+}
+
+fn print_digit(digit: &u8) {
+    println!("{}", digit);
+}
+
+fn print_mut_digit(digit: &mut u8) {
+    *digit += 1;
+    println!("{}", digit);
+}
+
+#[allow(unused)]
+pub fn run() {
+
+    /* CustomBox implements Deref
+       and has its own definition of deref,
+       so the following code compiles well
+       and the variable can be dereferenced */
+    let value = CustomBox::new(10);
+    println!("{}", *value);
+
+    /* OtherCustomBox does not implement Deref,
+       so using the dereferencing operator
+       on it does not work */
+    let value = OtherCustomBox::new(20);
+    //error: println!("{}", *value);
+    
+    /* deref coercion is a mechanism
+       that calls "deref()" when the object
+       is passed by reference */
+    let digit: u8 = 20;
+    let value = CustomBox::new(digit);
+    print_digit(&value);
+
+    /* deref coercion also works with mutable references,
+       the trait to use is DerefMut and the function to
+       define "deref_mut()"; the value into the box is changed */
+    let digit: u8 = 30;
+    let mut value = ThirdCustomBox::new(digit);
+    print_mut_digit(&mut value); // 31
+    println!("{}", *value); // 31
+
+    /* a shared reference cannot become a mutable one */
+    //error: print_mut_digit(&value);
+
+    /* MyBox really allocates its value on the heap,
+       and can hold values without a size known at compilation */
+    let (mut boxed, allocations) = alloc_counter::measure(|| MyBox::new(10u32));
+    *boxed += 1;
+    println!("{}", boxed); // 11
+    println!("{}", allocations); // allocations: 1, deallocations: 0, bytes: 4
+
+    let slice: MyBox<[u8]> = MyBox::from(vec![1, 2, 3]);
+    let text: MyBox<str> = MyBox::from("text");
+    let display: MyBox<dyn Display> = coerce_box!(MyBox::new(2.5) => dyn Display);
+    println!("{:?} {} {}", slice, text, display); // [1, 2, 3] text 2.5
+}
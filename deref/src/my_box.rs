@@ -0,0 +1,324 @@
+/* a real smart pointer: unlike `CustomBox`, `MyBox` allocates its value
+   on the heap with `std::alloc`, owns it, and frees it when dropped;
+
+   `T: ?Sized` allows dynamically sized values: slices, `str`, trait objects;
+   the pointer to such a value is a "fat" pointer, it carries the length
+   of the slice or the vtable of the trait object, so `Layout::for_value`
+   finds the size to free
+
+   the standard `Box` converts `Box<T>` into `Box<dyn Trait>` implicitly
+   thanks to the unstable `CoerceUnsized` trait, `MyBox` needs helpers */
+
+use std::alloc::{
+    self,
+    Layout,
+};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{
+    Deref,
+    DerefMut,
+};
+use std::ptr::{
+    self,
+    NonNull,
+};
+
+pub struct MyBox<T: ?Sized> {
+    pointer: NonNull<T>,
+
+    /* the box owns a T: dropping the box may drop a T (drop check) */
+    owned: PhantomData<T>,
+}
+
+/* like `Box`, the box can be sent or shared if its value can */
+unsafe impl<T: ?Sized + Send> Send for MyBox<T> {}
+unsafe impl<T: ?Sized + Sync> Sync for MyBox<T> {}
+
+/// Allocates memory for the layout, nothing for a zero-sized layout
+fn allocate(layout: Layout) -> NonNull<u8> {
+
+    if layout.size() == 0 {
+        /* any non-null aligned address is valid for zero bytes */
+        return NonNull::new(ptr::without_provenance_mut(layout.align())).unwrap();
+    }
+
+    let pointer = unsafe { alloc::alloc(layout) };
+    match NonNull::new(pointer) {
+        Some(pointer) => pointer,
+        None => alloc::handle_alloc_error(layout),
+    }
+}
+
+/// Frees the memory given by `allocate` for the same layout
+unsafe fn deallocate(
+    pointer: NonNull<u8>,
+    layout: Layout,
+) {
+    if layout.size() != 0 {
+        unsafe { alloc::dealloc(pointer.as_ptr(), layout) }
+    }
+}
+
+impl<T> MyBox<T> {
+
+    /// Moves the value to the heap
+    pub fn new(value: T) -> MyBox<T> {
+
+        let pointer = allocate(Layout::new::<T>()).cast::<T>();
+        unsafe {
+            pointer.as_ptr().write(value);
+        }
+
+        MyBox {
+            pointer,
+            owned: PhantomData,
+        }
+    }
+
+    /// Moves the value back to the stack and frees the memory
+    pub fn into_inner(boxed: MyBox<T>) -> T {
+
+        let pointer = MyBox::into_raw(boxed);
+        unsafe {
+            let value = pointer.read();
+            deallocate(NonNull::new_unchecked(pointer).cast(), Layout::new::<T>());
+            value
+        }
+    }
+}
+
+impl<T: ?Sized> MyBox<T> {
+
+    /// Gives the ownership of the value to the pointer,
+    /// the memory is not freed anymore
+    pub fn into_raw(boxed: MyBox<T>) -> *mut T {
+
+        let pointer = boxed.pointer.as_ptr();
+        mem::forget(boxed);
+        pointer
+    }
+
+    /// Takes the ownership of the value back
+    ///
+    /// # Safety
+    ///
+    /// The pointer must come from `MyBox::into_raw`, possibly converted
+    /// by an unsizing coercion (`*mut [T; N]` to `*mut [T]`, `*mut T` to `*mut dyn Trait`),
+    /// and must be given back once only
+    pub unsafe fn from_raw(pointer: *mut T) -> MyBox<T> {
+        MyBox {
+            pointer: unsafe { NonNull::new_unchecked(pointer) },
+            owned: PhantomData,
+        }
+    }
+}
+
+/// Converts a `MyBox<T>` into a `MyBox<dyn Trait>` (or any unsized type `T` coerces to):
+///
+/// ```
+/// use std::fmt::Display;
+/// use deref::coerce_box;
+/// use deref::my_box::MyBox;
+///
+/// let boxed: MyBox<dyn Display> = coerce_box!(MyBox::new(10) => dyn Display);
+/// assert_eq!(boxed.to_string(), "10");
+/// ```
+///
+/// The pointer is converted by an implicit coercion, not by `as`,
+/// so only the valid unsizing conversions compile:
+///
+/// ```compile_fail,E0308
+/// use deref::coerce_box;
+/// use deref::my_box::MyBox;
+///
+/// let boxed: MyBox<u64> = coerce_box!(MyBox::new(10u8) => u64);
+/// ```
+#[macro_export]
+macro_rules! coerce_box {
+    ($boxed:expr => $target:ty) => {{
+        /* the type of the box is inferred first, then its pointer is coerced */
+        let pointer = $crate::my_box::MyBox::into_raw($boxed);
+        let pointer: *mut $target = pointer;
+        unsafe { $crate::my_box::MyBox::<$target>::from_raw(pointer) }
+    }};
+}
+
+impl<T, const N: usize> MyBox<[T; N]> {
+
+    /// Forgets the length of the array into the type, it is kept into the pointer
+    pub fn into_slice(boxed: MyBox<[T; N]>) -> MyBox<[T]> {
+        let pointer: *mut [T] = MyBox::<[T; N]>::into_raw(boxed);
+        unsafe { MyBox::from_raw(pointer) }
+    }
+}
+
+/* drops the items already cloned if a clone panics,
+   then frees the memory */
+struct PartialSlice<T> {
+    pointer: NonNull<T>,
+    initialized: usize,
+    layout: Layout,
+}
+
+impl<T> Drop for PartialSlice<T> {
+
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.pointer.as_ptr(), self.initialized));
+            deallocate(self.pointer.cast(), self.layout);
+        }
+    }
+}
+
+impl<T: Clone> MyBox<[T]> {
+
+    /// Clones the items into a new allocation
+    pub fn from_slice(items: &[T]) -> MyBox<[T]> {
+
+        let layout = Layout::array::<T>(items.len()).unwrap();
+        let mut partial = PartialSlice {
+            pointer: allocate(layout).cast::<T>(),
+            initialized: 0,
+            layout,
+        };
+
+        for item in items {
+            unsafe {
+                partial.pointer.as_ptr().add(partial.initialized).write(item.clone());
+            }
+            partial.initialized += 1;
+        }
+
+        let pointer = ptr::slice_from_raw_parts_mut(partial.pointer.as_ptr(), items.len());
+        mem::forget(partial);
+        unsafe { MyBox::from_raw(pointer) }
+    }
+}
+
+impl<T> From<Vec<T>> for MyBox<[T]> {
+
+    /// Moves the items into a new allocation
+    fn from(mut items: Vec<T>) -> MyBox<[T]> {
+
+        let length = items.len();
+        let pointer = allocate(Layout::array::<T>(length).unwrap()).cast::<T>();
+
+        unsafe {
+            ptr::copy_nonoverlapping(items.as_ptr(), pointer.as_ptr(), length);
+
+            /* the items are moved, the vector only frees its memory */
+            items.set_len(0);
+
+            MyBox::from_raw(ptr::slice_from_raw_parts_mut(pointer.as_ptr(), length))
+        }
+    }
+}
+
+impl<T: Clone> From<&[T]> for MyBox<[T]> {
+
+    fn from(items: &[T]) -> MyBox<[T]> {
+        MyBox::from_slice(items)
+    }
+}
+
+impl From<&str> for MyBox<str> {
+
+    /// Copies the bytes, a `str` has the layout of a `[u8]`
+    fn from(text: &str) -> MyBox<str> {
+        let bytes = MyBox::into_raw(MyBox::from_slice(text.as_bytes()));
+        unsafe { MyBox::from_raw(bytes as *mut str) }
+    }
+}
+
+impl<T> From<T> for MyBox<T> {
+
+    fn from(value: T) -> MyBox<T> {
+        MyBox::new(value)
+    }
+}
+
+impl<T: ?Sized> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.pointer.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MyBox<T> {
+
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.pointer.as_mut() }
+    }
+}
+
+impl<T: ?Sized> Drop for MyBox<T> {
+
+    /* the layout is read from the value before it is dropped,
+       the memory is freed after */
+    fn drop(&mut self) {
+        let layout = Layout::for_value::<T>(self);
+        unsafe {
+            ptr::drop_in_place(self.pointer.as_ptr());
+            deallocate(self.pointer.cast(), layout);
+        }
+    }
+}
+
+impl<T: Clone> Clone for MyBox<T> {
+
+    fn clone(&self) -> MyBox<T> {
+        MyBox::new((**self).clone())
+    }
+}
+
+impl<T: Clone> Clone for MyBox<[T]> {
+
+    fn clone(&self) -> MyBox<[T]> {
+        MyBox::from_slice(self)
+    }
+}
+
+impl Clone for MyBox<str> {
+
+    fn clone(&self) -> MyBox<str> {
+        MyBox::from(&**self)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MyBox<T> {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        fmt::Debug::fmt(&**self, formatter)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for MyBox<T> {
+
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        fmt::Display::fmt(&**self, formatter)
+    }
+}
+
+impl<T: ?Sized + PartialEq> PartialEq for MyBox<T> {
+
+    fn eq(
+        &self,
+        other: &MyBox<T>,
+    ) -> bool
+    {
+        **self == **other
+    }
+}
+
+impl<T: ?Sized + Eq> Eq for MyBox<T> {}
@@ -0,0 +1,178 @@
+/* every deref coercion rule, one case per rule:
+
+   - &T to &U when T: Deref<Target = U>
+   - &mut T to &mut U when T: DerefMut<Target = U>
+   - &mut T to &U when T: Deref<Target = U>
+   - never &T to &mut U (compile_fail example of `ThirdCustomBox`)
+
+   the coercions are chained as long as the types do not match,
+   and a method call tries the receiver, then its dereferenced values */
+
+use std::ops::{
+    Deref,
+    DerefMut,
+};
+
+use deref::my_box::MyBox;
+use deref::{
+    CustomBox,
+    ThirdCustomBox,
+};
+
+fn read(digit: &u8) -> u8 {
+    *digit
+}
+
+fn increment(digit: &mut u8) -> u8 {
+    *digit += 1;
+    *digit
+}
+
+fn length(text: &str) -> usize {
+    text.len()
+}
+
+/* implemented by the wrapper and by the wrapped value,
+   to observe which one a method call picks */
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl Describe for u8 {
+
+    fn describe(&self) -> String {
+        format!("u8 {}", self)
+    }
+}
+
+impl<T> Describe for CustomBox<T> {
+
+    fn describe(&self) -> String {
+        String::from("custom box")
+    }
+}
+
+/* implemented by the value only, found through the wrapper */
+trait Double {
+    fn double(&self) -> u8;
+}
+
+impl Double for u8 {
+
+    fn double(&self) -> u8 {
+        self * 2
+    }
+}
+
+struct Case {
+    rule: &'static str,
+    check: fn() -> String,
+    expected: &'static str,
+}
+
+const CASES: &[Case] = &[
+    Case {
+        rule: "&T to &U",
+        check: || read(&CustomBox::new(20)).to_string(),
+        expected: "20",
+    },
+    Case {
+        rule: "&mut T to &mut U",
+        check: || {
+            let mut value = ThirdCustomBox::new(30);
+            increment(&mut value);
+            value.to_string()
+        },
+        expected: "31",
+    },
+    Case {
+        rule: "&mut T to &U",
+        check: || read(&mut ThirdCustomBox::new(40)).to_string(),
+        expected: "40",
+    },
+    Case {
+        rule: "&T to &U to &V, chained",
+        check: || read(&CustomBox::new(ThirdCustomBox::new(50))).to_string(),
+        expected: "50",
+    },
+    Case {
+        rule: "&mut T to &mut U to &mut V, chained",
+        check: || {
+            let mut value = ThirdCustomBox::new(ThirdCustomBox::new(60));
+            increment(&mut value);
+            (**value).to_string()
+        },
+        expected: "61",
+    },
+    Case {
+        rule: "&MyBox<CustomBox<String>> to &str, three derefs",
+        check: || length(&MyBox::new(CustomBox::new(String::from("four")))).to_string(),
+        expected: "4",
+    },
+    Case {
+        rule: "&MyBox<[u8]> to &[u8]",
+        check: || {
+            let bytes: MyBox<[u8]> = MyBox::from(vec![1, 2, 3]);
+            let slice: &[u8] = &bytes;
+            slice.len().to_string()
+        },
+        expected: "3",
+    },
+    Case {
+        rule: "method of the receiver first",
+        check: || CustomBox::new(7u8).describe(),
+        expected: "custom box",
+    },
+    Case {
+        rule: "method of the dereferenced value when asked",
+        check: || (*CustomBox::new(7u8)).describe(),
+        expected: "u8 7",
+    },
+    Case {
+        rule: "method found after the derefs",
+        check: || CustomBox::new(ThirdCustomBox::new(8u8)).double().to_string(),
+        expected: "16",
+    },
+    Case {
+        rule: "method of the wrapper through a reference to it",
+        check: || {
+            let reference: &CustomBox<u8> = &CustomBox::new(9);
+            reference.describe()
+        },
+        expected: "custom box",
+    },
+    Case {
+        rule: "inherent method of the target through the wrapper",
+        check: || CustomBox::new(String::from("text")).to_uppercase(),
+        expected: "TEXT",
+    },
+    Case {
+        rule: "mutable method of the target through DerefMut",
+        check: || {
+            let mut value = ThirdCustomBox::new(vec![1]);
+            value.push(2);
+            format!("{:?}", *value)
+        },
+        expected: "[1, 2]",
+    },
+];
+
+#[test]
+fn every_coercion_rule_applies() {
+
+    for case in CASES {
+        assert_eq!((case.check)(), case.expected, "{}", case.rule);
+    }
+}
+
+#[test]
+fn explicit_derefs_give_the_same_values() {
+
+    /* the coercions only insert the calls to deref and deref_mut */
+    let value = CustomBox::new(ThirdCustomBox::new(5u8));
+    assert_eq!(read(&value), read(value.deref().deref()));
+
+    let mut value = ThirdCustomBox::new(5u8);
+    assert_eq!(increment(&mut value), 6);
+    assert_eq!(increment(value.deref_mut()), 7);
+}
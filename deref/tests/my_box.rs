@@ -0,0 +1,242 @@
+/* every allocation is freed (counted by alloc_counter),
+   every value is dropped once, alignments are respected;
+   the same tests also run under Miri, that checks the unsafe code:
+
+       cargo +nightly miri test -p deref */
+
+use std::cell::Cell;
+use std::fmt::Display;
+use std::panic;
+use std::rc::Rc;
+
+use deref::coerce_box;
+use deref::my_box::MyBox;
+
+/* counts its drops into the shared counter */
+#[derive(Clone, Debug)]
+struct Tracked {
+    drops: Rc<Cell<usize>>,
+}
+
+impl Drop for Tracked {
+
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+/// Runs the function, checks that everything it allocated was freed
+fn without_leak<T, F: FnOnce() -> T>(function: F) -> T {
+
+    let (result, allocations) = alloc_counter::measure(function);
+    assert_eq!(allocations.allocations, allocations.deallocations, "{}", allocations);
+    result
+}
+
+#[test]
+fn values_are_allocated_once_and_freed() {
+
+    let allocations = without_leak(|| {
+        let (boxed, allocations) = alloc_counter::measure(|| MyBox::new(42u64));
+        assert_eq!(*boxed, 42);
+        allocations
+    });
+
+    assert_eq!(allocations.allocations, 1);
+    assert_eq!(allocations.bytes, 8);
+}
+
+#[test]
+fn values_are_dropped_once() {
+
+    let drops = Rc::new(Cell::new(0));
+
+    without_leak(|| {
+        let boxed = MyBox::new(Tracked { drops: drops.clone() });
+        let cloned = boxed.clone();
+        drop(boxed);
+        assert_eq!(drops.get(), 1);
+        drop(cloned);
+    });
+    assert_eq!(drops.get(), 2);
+
+    /* into_inner frees the memory but does not drop the value */
+    without_leak(|| {
+        let value = MyBox::into_inner(MyBox::new(Tracked { drops: drops.clone() }));
+        assert_eq!(drops.get(), 2);
+        drop(value);
+    });
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn deref_mut_changes_the_heap_value() {
+
+    without_leak(|| {
+        let mut boxed = MyBox::new(vec![1, 2]);
+        boxed.push(3);
+        *boxed = vec![boxed.iter().sum()];
+
+        assert_eq!(*boxed, vec![6]);
+        assert_eq!(boxed, MyBox::from(vec![6]));
+        assert_ne!(boxed, MyBox::new(vec![7]));
+        assert_eq!(format!("{:?}", boxed), "[6]");
+    });
+}
+
+#[test]
+fn slices_keep_their_length_into_the_pointer() {
+
+    let drops = Rc::new(Cell::new(0));
+
+    without_leak(|| {
+        let mut numbers: MyBox<[u32]> = MyBox::from(&[1, 2, 3][..]);
+        numbers[1] = 20;
+        assert_eq!(&*numbers, &[1, 20, 3]);
+        assert_eq!(numbers.clone(), numbers);
+
+        let array = MyBox::into_slice(MyBox::new([4u16; 5]));
+        assert_eq!(array.len(), 5);
+
+        let tracked: MyBox<[Tracked]> = MyBox::from(vec![Tracked { drops: drops.clone() }; 3]);
+        assert_eq!(tracked.len(), 3);
+    });
+
+    /* the items of the vector are moved, they are dropped with the box only */
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn strings_are_copied_to_the_heap() {
+
+    without_leak(|| {
+        let text: MyBox<str> = MyBox::from("hello");
+        let cloned = text.clone();
+
+        assert_eq!(&*text, "hello");
+        assert_eq!(text.to_uppercase(), "HELLO");
+        assert_eq!(cloned, text);
+        assert_eq!(format!("{} {:?}", text, text), "hello \"hello\"");
+    });
+}
+
+#[test]
+fn trait_objects_are_dropped_through_the_vtable() {
+
+    let drops = Rc::new(Cell::new(0));
+
+    without_leak(|| {
+        let displays: Vec<MyBox<dyn Display>> = vec![
+            coerce_box!(MyBox::new(10) => dyn Display),
+            coerce_box!(MyBox::new("text") => dyn Display),
+            coerce_box!(MyBox::new(2.5) => dyn Display),
+        ];
+        let texts: Vec<String> = displays.iter().map(|display| display.to_string()).collect();
+        assert_eq!(texts, vec!["10", "text", "2.5"]);
+
+        let tracked: MyBox<dyn std::any::Any> = coerce_box!(MyBox::new(Tracked { drops: drops.clone() }) => dyn std::any::Any);
+        assert!(tracked.is::<Tracked>());
+    });
+
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn zero_sized_values_do_not_allocate() {
+
+    let (boxes, allocations) = alloc_counter::measure(|| {
+        let unit = MyBox::new(());
+        let empty: MyBox<[u64]> = MyBox::from(Vec::new());
+        let units: MyBox<[()]> = MyBox::from_slice(&[(), (), ()]);
+        let text: MyBox<str> = MyBox::from("");
+        (unit, empty, units, text)
+    });
+
+    assert_eq!(allocations.allocations, 0);
+    assert_eq!(boxes.1.len(), 0);
+    assert_eq!(boxes.2.len(), 3);
+    assert!(boxes.3.is_empty());
+}
+
+#[test]
+fn alignment_is_respected() {
+
+    #[repr(align(64))]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Aligned(u8);
+
+    without_leak(|| {
+        let boxed = MyBox::new(Aligned(1));
+        assert_eq!(&*boxed as *const Aligned as usize % 64, 0);
+
+        let slice: MyBox<[Aligned]> = MyBox::from_slice(&[Aligned(1), Aligned(2)]);
+        assert_eq!(slice.as_ptr() as usize % 64, 0);
+
+        let zero_sized = MyBox::new([Aligned(0); 0]);
+        assert_eq!(zero_sized.as_ptr() as usize % 64, 0);
+    });
+}
+
+#[test]
+fn a_panicking_clone_leaks_nothing() {
+
+    /* the clone of the third item panics;
+       `resume_unwind` does not call the panic hook, nothing is printed */
+    struct Fragile {
+        index: usize,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Clone for Fragile {
+
+        fn clone(&self) -> Fragile {
+            if self.index == 2 {
+                panic::resume_unwind(Box::new("clone failed"));
+            }
+            Fragile {
+                index: self.index,
+                drops: self.drops.clone(),
+            }
+        }
+    }
+
+    impl Drop for Fragile {
+
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let items: Vec<Fragile> = (0..4)
+        .map(|index| Fragile {
+            index,
+            drops: drops.clone(),
+        })
+        .collect();
+
+    let failed = without_leak(|| panic::catch_unwind(panic::AssertUnwindSafe(|| MyBox::from_slice(&items))).is_err());
+
+    /* the two clones are dropped, the memory of the slice is freed */
+    assert!(failed);
+    assert_eq!(drops.get(), 2);
+
+    drop(items);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn raw_pointers_give_the_ownership_back() {
+
+    let drops = Rc::new(Cell::new(0));
+
+    without_leak(|| {
+        let pointer = MyBox::into_raw(MyBox::new(Tracked { drops: drops.clone() }));
+        assert_eq!(drops.get(), 0);
+
+        let boxed = unsafe { MyBox::from_raw(pointer) };
+        drop(boxed);
+    });
+
+    assert_eq!(drops.get(), 1);
+}
@@ -0,0 +1,23 @@
+#![allow(unused)]
+
+struct MyStructure {
+    value: u8,
+}
+
+impl Drop for MyStructure {
+
+    fn drop(&mut self) {
+        println!("the object goes out of the scope");
+    }
+}
+
+pub fn run() {
+
+    {
+        let object = MyStructure { value: 10 };
+        // print here
+    }
+
+    let object = MyStructure { value: 20 };
+    // print here
+}
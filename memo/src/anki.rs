@@ -0,0 +1,146 @@
+/* the memo as a flashcards deck importable into Anki:
+   the quiz questions, the explained pieces of code of every example
+   and the "To remember" and "Benefits" lists of the README */
+
+use crate::annotations;
+use crate::html;
+use crate::literate;
+use crate::quiz;
+use crate::readme;
+
+/* labels of the README lists exported as cards */
+const LISTS: &[(&str, &str)] = &[
+    ("To remember:", "What is there to remember?"),
+    ("Benefits:", "What are the benefits?"),
+    ("Advantages:", "What are the advantages?"),
+];
+
+/// One flashcard, its fields are HTML
+pub struct Card {
+
+    pub front: String,
+
+    pub back: String,
+
+    /// Name of the topic
+    pub tag: &'static str,
+}
+
+/// Returns the lines as an HTML block of code
+fn code_html<S: AsRef<str>>(lines: &[S]) -> String {
+
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|line| html::escape(line.as_ref()))
+        .collect();
+
+    format!("<pre>{}</pre>", lines.join("<br>"))
+}
+
+/// Returns the field without the characters
+/// that would break the tab-separated format
+pub fn field(text: &str) -> String {
+    text.replace(['\t', '\r'], " ").replace('\n', "<br>")
+}
+
+/// Returns the cards of the quiz questions of the topic
+pub fn question_cards(
+    topic: &'static str,
+    source: &str,
+) -> Vec<Card>
+{
+    quiz::questions(topic, source)
+        .into_iter()
+        .map(|question| Card {
+            front: format!("{}{}", code_html(&question.code), html::escape(question.prompt())),
+            back: html::escape(&question.explanation),
+            tag: topic,
+        })
+        .collect()
+}
+
+/// Returns a card per explained piece of code of the topic:
+/// the code on the front, the explanation and the annotations on the back
+pub fn section_cards(
+    topic: &'static str,
+    source: &str,
+) -> Vec<Card>
+{
+    literate::sections(source)
+        .into_iter()
+        .filter(|section| !section.prose.is_empty() && !section.code.is_empty())
+        .map(|section| {
+
+            let code: Vec<&str> = section
+                .code
+                .iter()
+                .filter_map(|line| annotations::without_comment(line))
+                .collect();
+
+            /* the comments of the code are the expected errors and outputs */
+            let annotations: Vec<String> = section
+                .code
+                .iter()
+                .filter_map(|line| annotations::comment(line))
+                .map(html::escape)
+                .collect();
+
+            let mut back = html::escape(&section.prose);
+            for annotation in annotations {
+                back.push_str(&format!("<br><code>{}</code>", annotation));
+            }
+
+            Card {
+                front: format!("{}What does this code show?", code_html(&code)),
+                back,
+                tag: topic,
+            }
+        })
+        .collect()
+}
+
+/// Returns a card per list of the README section of the topic
+pub fn list_cards(
+    readme: &str,
+    topic: &'static str,
+    title: &str,
+) -> Vec<Card>
+{
+    let Some(section) = readme::section(readme, title) else {
+        return Vec::new();
+    };
+
+    LISTS
+        .iter()
+        .filter_map(|(label, question)| {
+
+            let items = readme::bullet_list(&section, label);
+            if items.is_empty() {
+                return None;
+            }
+
+            let items: Vec<String> = items
+                .iter()
+                .map(|item| format!("<li>{}</li>", html::inline(item)))
+                .collect();
+
+            Some(Card {
+                front: format!("<b>{}</b><br>{}", html::inline(title), question),
+                back: format!("<ul>{}</ul>", items.concat()),
+                tag: topic,
+            })
+        })
+        .collect()
+}
+
+/// Returns the deck file: the headers telling Anki how to read it,
+/// then a line per card
+pub fn deck(cards: &[Card]) -> String {
+
+    let mut deck = String::from("#separator:tab\n#html:true\n#deck:rust-memo\n#tags column:3\n");
+    for card in cards {
+        deck.push_str(&format!("{}\t{}\t{}\n", field(&card.front), field(&card.back), card.tag));
+    }
+
+    deck
+}
@@ -0,0 +1,216 @@
+/* the examples document the lines that are rejected
+   as comments, for instance:
+
+       //error: println!("{}", text);
+       // error: "variable" is already borrowed: third_function(variable);
+
+   this module finds those comments and restores them into the code;
+   the lines failing at execution are documented by other comments,
+   like `// panics at execution: ...`, they are not restored */
+
+/// Comment prefixes of the documented rejected lines
+const ERROR_MARKERS: &[&str] = &[
+    "error:",
+    "compilation error:",
+    "compilation fails:",
+    "forbidden:",
+];
+
+/// A commented line documented as rejected
+pub struct ErrorComment {
+
+    /// Index of the line into the source
+    pub line: usize,
+
+    /// Content of the comment after the marker
+    pub text: String,
+
+    /// The comment is itself into a commented block of code
+    pub nested: bool,
+}
+
+/// Where the restored code goes into the surrounding function
+#[derive(Clone, Copy)]
+pub enum Splice {
+
+    /// The code is written in place of the comment
+    Insert,
+
+    /// The code replaces the comment and the end of the function body,
+    /// used when the comment is an alternative to the returned expression
+    Tail,
+}
+
+/// Returns the position of the "//" starting the comment of the line,
+/// the "//" of string literals are skipped
+fn comment_start(line: &str) -> Option<usize> {
+
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut previous = None;
+
+    for (index, character) in line.char_indices() {
+
+        match character {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '/' if !in_string && previous == Some('/') => return Some(index - 1),
+            _ => {}
+        }
+
+        previous = Some(character).filter(|_| !in_string);
+    }
+
+    None
+}
+
+/// Returns the content following the "//" of a line comment
+fn strip_comment(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix("//")
+}
+
+/// Returns the amount of leading whitespaces of the line
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Returns every comment of the source documenting a rejected line
+pub fn error_comments(source: &str) -> Vec<ErrorComment> {
+
+    let mut comments = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+
+        let Some(content) = strip_comment(line) else {
+            continue;
+        };
+
+        let (content, nested) = match strip_comment(content) {
+            Some(inner) => (inner, true),
+            None => (content, false),
+        };
+
+        let content = content.trim_start();
+        let marker = ERROR_MARKERS
+            .iter()
+            .find(|marker| content.starts_with(**marker));
+
+        if let Some(marker) = marker {
+            comments.push(ErrorComment {
+                line: index,
+                text: content[marker.len()..].trim().to_string(),
+                nested,
+            });
+        }
+    }
+
+    comments
+}
+
+/// Returns the source with the given code restored
+/// in place of the error comment
+pub fn restore(
+    source: &str,
+    comment: &ErrorComment,
+    code: &str,
+    splice: Splice,
+) -> String
+{
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    /* a nested comment only makes sense with its whole block,
+       so every line of the commented block is uncommented first */
+    if comment.nested {
+
+        let column = indentation(&lines[comment.line]);
+        let in_block = |line: &String| {
+            indentation(line) == column && line[column..].starts_with("//")
+        };
+
+        let mut first = comment.line;
+        while first > 0 && in_block(&lines[first - 1]) {
+            first -= 1;
+        }
+
+        let mut last = comment.line;
+        while last + 1 < lines.len() && in_block(&lines[last + 1]) {
+            last += 1;
+        }
+
+        for line in &mut lines[first..=last] {
+            line.replace_range(column..column + 2, "  ");
+        }
+    }
+
+    let indent = " ".repeat(indentation(&lines[comment.line]));
+    let restored: Vec<String> = code
+        .lines()
+        .map(|code_line| format!("{}{}", indent, code_line))
+        .collect();
+
+    let end = match splice {
+        Splice::Insert => comment.line + 1,
+        Splice::Tail => (comment.line..lines.len())
+            .find(|index| {
+                let line = &lines[*index];
+                indentation(line) < indent.len() && line.trim() == "}"
+            })
+            .unwrap_or(lines.len()),
+    };
+
+    lines.splice(comment.line..end, restored);
+    lines.join("\n")
+}
+
+/// A printed line documented with its expected output,
+/// for instance `println!("{}", first); // 10`
+pub struct OutputComment {
+
+    /// Index of the line into the source
+    pub line: usize,
+
+    /// Expected output, as written into the comment
+    pub output: String,
+}
+
+/// Returns every "println!" line of the source followed by its expected output;
+/// comments with quotes are remarks about the code, not outputs
+pub fn output_comments(source: &str) -> Vec<OutputComment> {
+
+    source
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+
+            let start = comment_start(line)?;
+            let code = &line[..start];
+            let output = line[start + 2..].trim();
+
+            let is_output = code.trim_start().starts_with("println!")
+                && !output.is_empty()
+                && !output.contains('"');
+
+            is_output.then(|| OutputComment {
+                line: index,
+                output: output.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the content of the trailing "//" comment of the line, trimmed
+pub fn comment(line: &str) -> Option<&str> {
+    comment_start(line).map(|start| line[start + 2..].trim())
+}
+
+/// Returns the line without its trailing "//" comment,
+/// or nothing if the whole line is a comment
+pub fn without_comment(line: &str) -> Option<&str> {
+
+    match comment_start(line) {
+        Some(start) if line[..start].trim().is_empty() => None,
+        Some(start) => Some(line[..start].trim_end()),
+        None => Some(line),
+    }
+}
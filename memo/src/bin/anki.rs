@@ -0,0 +1,40 @@
+/* exports the memo as a flashcards deck importable into Anki,
+   see the anki module
+
+   `cargo run -p memo --bin anki -- [deck file]`,
+   the deck is written on the standard output by default */
+
+use std::env;
+use std::fs;
+use std::io::{
+    self,
+    Write,
+};
+
+use memo::anki;
+use memo::readme;
+use memo::topics::TOPICS;
+
+fn main() -> io::Result<()> {
+
+    let mut cards = Vec::new();
+
+    for topic in TOPICS {
+        let source = fs::read_to_string(topic.directory().join("src/lib.rs"))?;
+        cards.extend(anki::list_cards(readme::README, topic.name, topic.title));
+        cards.extend(anki::section_cards(topic.name, &source));
+        cards.extend(anki::question_cards(topic.name, &source));
+    }
+
+    let deck = anki::deck(&cards);
+
+    match env::args().nth(1) {
+        Some(path) => {
+            fs::write(&path, deck)?;
+            eprintln!("{} cards written into {}", cards.len(), path);
+        }
+        None => io::stdout().write_all(deck.as_bytes())?,
+    }
+
+    Ok(())
+}
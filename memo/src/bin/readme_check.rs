@@ -0,0 +1,31 @@
+/* checks that the README and the example projects are synchronized,
+   see the readme_check module; also run by the tests of the memo
+
+   `cargo run -p memo --bin readme_check` */
+
+use std::fs;
+use std::process;
+
+use memo::readme_check;
+use memo::topics;
+
+fn main() {
+
+    let root = topics::repository();
+    let readme = fs::read_to_string(root.join("README.md")).expect("README.md cannot be read");
+    let issues = readme_check::check(&root, &readme);
+
+    for issue in &issues {
+        println!("{}", issue);
+    }
+
+    if !issues.is_empty() {
+        eprintln!("{} issues found", issues.len());
+        process::exit(1);
+    }
+
+    println!(
+        "README.md is synchronized with the {} projects",
+        readme_check::project_folders(&root).len(),
+    );
+}
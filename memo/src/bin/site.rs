@@ -0,0 +1,264 @@
+/* generates a static website of the memo, browsable offline:
+   one page per topic with its README section and its annotated source,
+   the explanations of the source are displayed next to the code
+
+   `cargo run -p memo --bin site -- [output folder]`,
+   the default output folder is "target/site" */
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use memo::html;
+use memo::literate;
+use memo::readme;
+use memo::topics::{
+    self,
+    Topic,
+    TOPICS,
+};
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 0 auto; max-width: 72em; padding: 0 1em; }
+nav { display: flex; gap: 1em; align-items: baseline; border-bottom: 1px solid #ccc; padding: 0.5em 0; }
+nav .search { margin-left: auto; position: relative; }
+#search-results { position: absolute; right: 0; background: #fff; border: 1px solid #ccc; list-style: none; margin: 0; padding: 0.5em 1em; min-width: 16em; }
+#search-results:empty { display: none; }
+pre { background: #f6f8fa; padding: 0.75em; overflow-x: auto; }
+code { font-family: monospace; }
+img { max-width: 100%; }
+.literate { display: grid; grid-template-columns: 1fr 2fr; gap: 1em; border-top: 1px solid #eee; }
+.literate .prose { padding-top: 0.75em; }
+";
+
+const SEARCH_SCRIPT: &str = "\
+(function () {
+    var input = document.getElementById('search');
+    var results = document.getElementById('search-results');
+
+    input.addEventListener('input', function () {
+        var words = input.value.toLowerCase().split(/\\s+/).filter(Boolean);
+        results.innerHTML = '';
+
+        SEARCH_INDEX.forEach(function (entry) {
+            var text = entry.text.toLowerCase();
+            var found = words.length > 0 && words.every(function (word) {
+                return text.indexOf(word) !== -1;
+            });
+
+            if (found) {
+                var item = document.createElement('li');
+                var link = document.createElement('a');
+                link.href = entry.page;
+                link.textContent = entry.title;
+                item.appendChild(link);
+                results.appendChild(item);
+            }
+        });
+    });
+})();
+";
+
+/// Returns the file name of the topic page
+fn page_name(topic: &Topic) -> String {
+    format!("{}.html", topic.name)
+}
+
+/// Returns a whole HTML page with the navigation bar and the search field
+fn page(
+    title: &str,
+    navigation: &str,
+    body: &str,
+) -> String
+{
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>{title} - rust-memo</title>
+<link rel=\"stylesheet\" href=\"style.css\">
+</head>
+<body>
+<nav>
+<a href=\"index.html\">rust-memo</a>
+{navigation}
+<div class=\"search\">
+<input id=\"search\" type=\"search\" placeholder=\"Search\">
+<ul id=\"search-results\"></ul>
+</div>
+</nav>
+{body}
+<script src=\"search-index.js\"></script>
+<script src=\"search.js\"></script>
+</body>
+</html>
+",
+        title = html::escape(&title.replace('`', "")),
+    )
+}
+
+/// Returns the links to the previous and the next topics
+fn navigation(index: usize) -> String {
+
+    let mut links = String::new();
+
+    if let Some(previous) = index.checked_sub(1).map(|previous| &TOPICS[previous]) {
+        links.push_str(&format!(
+            "<a href=\"{}\">&larr; {}</a>\n",
+            page_name(previous),
+            html::inline(previous.title),
+        ));
+    }
+
+    if let Some(next) = TOPICS.get(index + 1) {
+        links.push_str(&format!(
+            "<a href=\"{}\">{} &rarr;</a>\n",
+            page_name(next),
+            html::inline(next.title),
+        ));
+    }
+
+    links
+}
+
+/// Returns the annotated source of the topic, explanations next to the code
+fn literate_source(source: &str) -> String {
+
+    let mut body = String::new();
+
+    for section in literate::sections(source) {
+
+        let code: Vec<&str> = section.code.iter().map(String::as_str).collect();
+
+        body.push_str(&format!(
+            "<div class=\"literate\">\n<div class=\"prose\">{}</div>\n<div class=\"code\">{}</div>\n</div>\n",
+            html::escape(&section.prose).replace("\n\n", "<br><br>"),
+            if code.is_empty() {
+                String::new()
+            } else {
+                html::code_block_html("rust", &code)
+            },
+        ));
+    }
+
+    body
+}
+
+/// Returns the text as a JSON string
+fn json_string(text: &str) -> String {
+
+    let mut json = String::from("\"");
+
+    for character in text.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '<' => json.push_str("\\u003c"),
+            _ if character.is_control() => json.push_str(&format!("\\u{:04x}", character as u32)),
+            _ => json.push(character),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+/// Copies the images referenced by the README
+fn copy_images(
+    source: &Path,
+    destination: &Path,
+) -> io::Result<()>
+{
+    fs::create_dir_all(destination)?;
+
+    for entry in fs::read_dir(source)? {
+        let path = entry?.path();
+        if let Some(name) = path.file_name() {
+            fs::copy(&path, destination.join(name))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+
+    let root = topics::repository();
+    let output = env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root.join("target/site"));
+
+    fs::create_dir_all(&output)?;
+
+    let mut search_entries = Vec::new();
+    let mut topic_links = String::new();
+
+    for (index, topic) in TOPICS.iter().enumerate() {
+
+        let section = readme::section(readme::README, topic.title).unwrap_or_default();
+        let source = fs::read_to_string(topic.directory().join("src/lib.rs"))?;
+
+        let body = format!(
+            "{}\n<h2>Source: <code>{}/src/lib.rs</code></h2>\n{}",
+            html::markdown(&section),
+            topic.name,
+            literate_source(&source),
+        );
+
+        fs::write(
+            output.join(page_name(topic)),
+            page(topic.title, &navigation(index), &body),
+        )?;
+
+        /* the explanations of the source are searchable too */
+        let prose: Vec<String> = literate::sections(&source)
+            .into_iter()
+            .map(|section| section.prose)
+            .collect();
+
+        search_entries.push(format!(
+            "{{\"page\": {}, \"title\": {}, \"text\": {}}}",
+            json_string(&page_name(topic)),
+            json_string(topic.title),
+            json_string(&format!("{}\n{}\n{}", topic.name, section.join("\n"), prose.join("\n"))),
+        ));
+
+        topic_links.push_str(&format!(
+            "<li><a href=\"{}\">{}</a></li>\n",
+            page_name(topic),
+            html::inline(topic.title),
+        ));
+    }
+
+    /* the index is the introduction of the README and the list of topics */
+    let introduction: Vec<&str> = readme::README
+        .lines()
+        .take_while(|line| readme::parse_heading(line) != Some((2, "Table of contents")))
+        .collect();
+
+    let index = format!(
+        "{}\n<h2>Topics</h2>\n<ul>\n{}</ul>\n",
+        html::markdown(&introduction),
+        topic_links,
+    );
+    fs::write(output.join("index.html"), page("Index", "", &index))?;
+
+    fs::write(output.join("style.css"), STYLE)?;
+    fs::write(output.join("search.js"), SEARCH_SCRIPT)?;
+    fs::write(
+        output.join("search-index.js"),
+        format!("const SEARCH_INDEX = [\n{}\n];\n", search_entries.join(",\n")),
+    )?;
+
+    copy_images(&root.join("images"), &output.join("images"))?;
+
+    println!("{} pages generated into {}", TOPICS.len() + 1, output.display());
+    Ok(())
+}
@@ -0,0 +1,241 @@
+use crate::annotations::Splice;
+
+/// What the compiler does with a restored error line
+pub enum Verdict {
+
+    /// The code is rejected with the given error code
+    Error(&'static str),
+
+    /// The code is rejected by the parser (syntax errors have no code)
+    SyntaxError,
+}
+
+/// Expected outcome of one documented error line
+pub struct KnownError {
+
+    /// Name of the project folder
+    pub topic: &'static str,
+
+    /// Code restored in place of the comment,
+    /// its first line must be part of the comment
+    pub code: &'static str,
+
+    /// Where the code goes into the surrounding function
+    pub splice: Splice,
+
+    pub verdict: Verdict,
+}
+
+/* every documented error line of the examples,
+   in the order of their appearance into the sources */
+pub const KNOWN_ERRORS: &[KnownError] = &[
+    KnownError {
+        topic: "constants",
+        code: "const RESULT: u8 = first_variable;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0435"),
+    },
+    KnownError {
+        topic: "constants",
+        code: "VALUE = 30;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0070"),
+    },
+    KnownError {
+        topic: "constants",
+        code: "let nonsense = distance + duration;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0308"),
+    },
+    KnownError {
+        topic: "constants",
+        code: "const INVALID: ConfigKey = ConfigKey::new(\"Server.Timeout\");",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0080"),
+    },
+    KnownError {
+        topic: "shadowing",
+        code: "third = 40;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0384"),
+    },
+    KnownError {
+        topic: "compound_types",
+        code: "tuple.0 = 5;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0594"),
+    },
+    KnownError {
+        topic: "compound_types",
+        code: "array[0] = 10;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0594"),
+    },
+    KnownError {
+        topic: "compound_types",
+        code: "let sum = first + second;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0308"),
+    },
+    KnownError {
+        topic: "expressions_and_statements",
+        code: "10 + 5;",
+        splice: Splice::Tail,
+        verdict: Verdict::Error("E0308"),
+    },
+    KnownError {
+        topic: "expressions_and_statements",
+        code: "let value = (let other = 10);",
+        splice: Splice::Insert,
+        verdict: Verdict::SyntaxError,
+    },
+    KnownError {
+        topic: "ownership",
+        code: "println!(\"{}\", text);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0382"),
+    },
+    KnownError {
+        topic: "ownership",
+        code: "println!(\"{}\", string);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0382"),
+    },
+    KnownError {
+        topic: "references",
+        code: "let other_reference = &mut value;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0499"),
+    },
+    KnownError {
+        topic: "references",
+        code: "let other_reference = &value;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0502"),
+    },
+    KnownError {
+        topic: "references",
+        code: "reference = &other_variable;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0597"),
+    },
+    KnownError {
+        topic: "references",
+        code: "variable.value = 10;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0506"),
+    },
+    KnownError {
+        topic: "references",
+        code: "third_function(variable);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0505"),
+    },
+    KnownError {
+        topic: "references",
+        code: "second_function(&mut variable);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0499"),
+    },
+    KnownError {
+        topic: "references",
+        code: "variable.value = 30;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0506"),
+    },
+    KnownError {
+        topic: "references",
+        code: "third_function(variable);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0505"),
+    },
+    KnownError {
+        topic: "lifetimes",
+        code: "reference = &value;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0597"),
+    },
+    KnownError {
+        topic: "lifetimes",
+        code: "reference = get_highest(\n    &first,\n    &second,\n);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0597"),
+    },
+    KnownError {
+        topic: "lifetimes",
+        code: "object.reference = &other_value;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0597"),
+    },
+    KnownError {
+        topic: "lifetimes",
+        code: "let reference: &'static i32 = &value;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0597"),
+    },
+    KnownError {
+        topic: "trait_bounds",
+        code: "third_object.get_first_value();",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0599"),
+    },
+    KnownError {
+        topic: "closures",
+        code: "println!(\"{}\", my_string);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0382"),
+    },
+    KnownError {
+        topic: "closures",
+        code: "println!(\"{}\", my_string);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0382"),
+    },
+    KnownError {
+        topic: "box_pointer",
+        code: "println!(\"{}\", first.first);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0382"),
+    },
+    KnownError {
+        topic: "deref",
+        code: "println!(\"{}\", *value);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0614"),
+    },
+    KnownError {
+        topic: "deref",
+        code: "print_mut_digit(&value);",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0308"),
+    },
+    KnownError {
+        topic: "refcell",
+        code: "self.value = 20;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0594"),
+    },
+    KnownError {
+        topic: "refcell",
+        code: "let second_immutable_reference = &mut value;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0499"),
+    },
+    KnownError {
+        topic: "refcell",
+        code: "*value = 20;",
+        splice: Splice::Insert,
+        verdict: Verdict::Error("E0594"),
+    },
+    KnownError {
+        topic: "replace",
+        code: "self.array",
+        splice: Splice::Tail,
+        verdict: Verdict::Error("E0507"),
+    },
+];
+
+/// Returns the known errors of the topic, in the order of the source
+pub fn of_topic(topic: &str) -> impl Iterator<Item = &'static KnownError> + '_ {
+    KNOWN_ERRORS.iter().filter(move |error| error.topic == topic)
+}
@@ -0,0 +1,243 @@
+/* conversion of the README markdown into HTML,
+   limited to what the memo uses: headings, paragraphs, lists,
+   fenced code blocks, inline code, emphasis, links, images
+   and one line comments */
+
+use crate::readme;
+
+/// Returns the text with the HTML special characters escaped
+pub fn escape(text: &str) -> String {
+
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Returns the "[text](target)" link starting the text:
+/// its text, its target and its length
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+
+    if !text.starts_with('[') {
+        return None;
+    }
+
+    let label_end = text.find("](").filter(|label_end| *label_end >= 1)?;
+    let target_end = label_end + text[label_end..].find(')')?;
+
+    Some((
+        &text[1..label_end],
+        &text[label_end + 2..target_end],
+        target_end + 1,
+    ))
+}
+
+/// Returns the HTML of one line of markdown text
+pub fn inline(text: &str) -> String {
+
+    let mut html = String::new();
+    let mut rest = text;
+
+    while let Some(character) = rest.chars().next() {
+
+        if let Some(code) = rest.strip_prefix('`') {
+            if let Some(end) = code.find('`') {
+                html.push_str(&format!("<code>{}</code>", escape(&code[..end])));
+                rest = &code[end + 1..];
+                continue;
+            }
+        }
+
+        if let Some(strong) = rest.strip_prefix("**") {
+            if let Some(end) = strong.find("**") {
+                html.push_str(&format!("<strong>{}</strong>", inline(&strong[..end])));
+                rest = &strong[end + 2..];
+                continue;
+            }
+        }
+
+        if let Some(image) = rest.strip_prefix('!') {
+            if let Some((alternative, source, length)) = parse_link(image) {
+                html.push_str(&format!(
+                    "<img src=\"{}\" alt=\"{}\">",
+                    escape(source),
+                    escape(alternative),
+                ));
+                rest = &image[length..];
+                continue;
+            }
+        }
+
+        if rest.starts_with('[') {
+            if let Some((label, target, length)) = parse_link(rest) {
+                html.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape(target),
+                    inline(label),
+                ));
+                rest = &rest[length..];
+                continue;
+            }
+        }
+
+        html.push_str(&escape(&character.to_string()));
+        rest = &rest[character.len_utf8()..];
+    }
+
+    html
+}
+
+/// Returns the HTML of a block of code
+pub fn code_block_html(
+    language: &str,
+    lines: &[&str],
+) -> String
+{
+    format!(
+        "<pre><code class=\"language-{}\">{}</code></pre>\n",
+        escape(language),
+        escape(&lines.join("\n")),
+    )
+}
+
+/// Returns the item text if the line is a list item, and its indentation
+fn list_item(line: &str) -> Option<(usize, &str)> {
+
+    let trimmed = line.trim_start();
+    let text = trimmed
+        .strip_prefix("* ")
+        .or_else(|| trimmed.strip_prefix("- "))?;
+
+    Some((line.len() - trimmed.len(), text))
+}
+
+/// Converts markdown lines into HTML
+struct Renderer {
+    html: String,
+    paragraph: Vec<String>,
+
+    /* indentation of every opened list, the deepest last */
+    lists: Vec<usize>,
+}
+
+impl Renderer {
+
+    fn close_paragraph(&mut self) {
+
+        if !self.paragraph.is_empty() {
+            self.html.push_str(&format!("<p>{}</p>\n", self.paragraph.join("\n")));
+            self.paragraph.clear();
+        }
+    }
+
+    fn close_lists(&mut self) {
+
+        while self.lists.pop().is_some() {
+            self.html.push_str("</li></ul>\n");
+        }
+    }
+
+    fn close_blocks(&mut self) {
+        self.close_paragraph();
+        self.close_lists();
+    }
+
+    fn list_item(
+        &mut self,
+        indentation: usize,
+        text: &str,
+    ) {
+        self.close_paragraph();
+
+        while self.lists.last().is_some_and(|list| *list > indentation) {
+            self.lists.pop();
+            self.html.push_str("</li></ul>\n");
+        }
+
+        if self.lists.last().is_some_and(|list| *list == indentation) {
+            self.html.push_str("</li>\n<li>");
+        } else {
+            self.lists.push(indentation);
+            self.html.push_str("<ul>\n<li>");
+        }
+
+        self.html.push_str(&inline(text));
+    }
+}
+
+/// Returns the HTML of the markdown lines
+pub fn markdown(lines: &[&str]) -> String {
+
+    let mut renderer = Renderer {
+        html: String::new(),
+        paragraph: Vec::new(),
+        lists: Vec::new(),
+    };
+
+    let mut code_block: Option<(&str, Vec<&str>)> = None;
+
+    for line in lines {
+
+        if let Some(fence) = line.strip_prefix("```") {
+            match code_block.take() {
+                Some((language, code)) => {
+                    renderer.html.push_str(&code_block_html(language, &code));
+                }
+                None => {
+                    renderer.close_blocks();
+                    code_block = Some((fence.trim(), Vec::new()));
+                }
+            }
+            continue;
+        }
+
+        if let Some((_, code)) = code_block.as_mut() {
+            code.push(line);
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            renderer.close_blocks();
+        } else if line.starts_with("<!--") && line.trim_end().ends_with("-->") {
+
+            /* one line comments (the markers of readme_check) are not displayed */
+            continue;
+        } else if let Some((level, title)) = readme::parse_heading(line) {
+            renderer.close_blocks();
+            renderer.html.push_str(&format!(
+                "<h{} id=\"{}\">{}</h{}>\n",
+                level,
+                escape(&readme::anchor(title)),
+                inline(title),
+                level,
+            ));
+        } else if let Some((indentation, text)) = list_item(line) {
+            renderer.list_item(indentation, text);
+        } else if !renderer.lists.is_empty() {
+
+            /* continuation of the current list item */
+            renderer.html.push('\n');
+            renderer.html.push_str(&inline(line.trim()));
+        } else {
+            renderer.paragraph.push(inline(line));
+        }
+    }
+
+    renderer.close_blocks();
+
+    /* a code block not closed goes until the end */
+    if let Some((language, code)) = code_block {
+        renderer.html.push_str(&code_block_html(language, &code));
+    }
+
+    renderer.html
+}
@@ -0,0 +1,13 @@
+/* shared pieces of the "memo" runner:
+   the list of the example projects,
+   the README parsing, the annotations of the sources
+   and the tools built upon them */
+pub mod anki;
+pub mod annotations;
+pub mod compile_errors;
+pub mod html;
+pub mod literate;
+pub mod quiz;
+pub mod readme;
+pub mod readme_check;
+pub mod topics;
@@ -0,0 +1,133 @@
+/* the examples explain their code with block comments
+   written just before the lines they describe:
+
+       /* a Box<T> is moved by default */
+       let first = Box::new(...);
+
+   this module splits a source into explanations and their code */
+
+use std::mem;
+
+/// An explanation of the source and the code it describes
+pub struct Section {
+
+    /// Content of the block comments, empty for code without explanation
+    pub prose: String,
+
+    /// Index of the first line of code into the source
+    pub line: usize,
+
+    /// Lines of code, without their common indentation
+    pub code: Vec<String>,
+}
+
+/// Returns the block comment content of the line,
+/// and what follows the end of the comment if the comment ends on this line
+fn comment_content(line: &str) -> (&str, Option<&str>) {
+
+    match line.find("*/") {
+        Some(end) => (line[..end].trim(), Some(&line[end + 2..])),
+        None => (line.trim(), None),
+    }
+}
+
+/// Removes the blank lines around the code and its common indentation
+fn tidy(section: &mut Section) {
+
+    while section.code.last().is_some_and(|line| line.trim().is_empty()) {
+        section.code.pop();
+    }
+
+    let leading = section
+        .code
+        .iter()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    section.code.drain(..leading);
+    section.line += leading;
+
+    let indentation = section
+        .code
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    for line in &mut section.code {
+        *line = line.get(indentation..).unwrap_or("").to_string();
+    }
+}
+
+/// Returns the sections of the source, in order
+pub fn sections(source: &str) -> Vec<Section> {
+
+    let mut sections = Vec::new();
+    let mut current = Section {
+        prose: String::new(),
+        line: 0,
+        code: Vec::new(),
+    };
+    let mut in_comment = false;
+
+    for (index, line) in source.lines().enumerate() {
+
+        let trimmed = line.trim_start();
+
+        let content = if in_comment {
+            Some(line)
+        } else if let Some(comment) = trimmed.strip_prefix("/*") {
+
+            /* a new explanation closes the code of the previous one,
+               two explanations in a row are joined */
+            if current.code.iter().any(|code| !code.trim().is_empty()) {
+                sections.push(mem::replace(
+                    &mut current,
+                    Section {
+                        prose: String::new(),
+                        line: index,
+                        code: Vec::new(),
+                    },
+                ));
+            } else if !current.prose.is_empty() {
+                current.prose.push_str("\n\n");
+            }
+            Some(comment)
+        } else {
+            None
+        };
+
+        match content {
+            Some(content) => {
+                let (text, after) = comment_content(content);
+                if !text.is_empty() {
+                    if !current.prose.is_empty() && !current.prose.ends_with('\n') {
+                        current.prose.push(' ');
+                    }
+                    current.prose.push_str(text);
+                }
+                in_comment = after.is_none();
+                current.line = index + 1;
+                current.code.clear();
+
+                /* code following the end of the comment on the same line,
+                   kept with the indentation of the line */
+                if let Some(code) = after.map(str::trim).filter(|code| !code.is_empty()) {
+                    let indentation = &line[..line.len() - trimmed.len()];
+                    current.line = index;
+                    current.code.push(format!("{}{}", indentation, code));
+                }
+            }
+            None => current.code.push(line.to_string()),
+        }
+    }
+
+    sections.push(current);
+
+    for section in &mut sections {
+        tidy(section);
+    }
+
+    sections.retain(|section| !section.prose.is_empty() || !section.code.is_empty());
+    sections
+}
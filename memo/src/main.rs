@@ -0,0 +1,163 @@
+use std::env;
+use std::fs;
+use std::io::{
+    self,
+    Write,
+};
+use std::path::PathBuf;
+use std::process;
+
+use memo::quiz::{
+    self,
+    Question,
+    Scores,
+};
+use memo::readme;
+use memo::topics::{
+    self,
+    Topic,
+    TOPICS,
+};
+
+const USAGE: &str = "usage: memo [list | run <topic> | quiz [<topic> | --scores] | <topic>]";
+
+/// Prints every available topic
+fn list() {
+
+    for topic in TOPICS {
+        println!("{:<30} {}", topic.name, topic.title);
+    }
+}
+
+/// Prints the README section of the topic and runs its example
+fn show(topic: &Topic) {
+
+    match readme::section(readme::README, topic.title) {
+        Some(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        None => println!("(no README section \"{}\")", topic.title),
+    }
+
+    println!();
+    println!("---- output of `{}` ----", topic.name);
+    (topic.run)();
+}
+
+/// Returns the topic with the given name, exits if it does not exist
+fn find_topic(name: &str) -> &'static Topic {
+
+    topics::find(name).unwrap_or_else(|| {
+        eprintln!("unknown topic \"{}\", run `memo list`", name);
+        process::exit(1);
+    })
+}
+
+/// Returns the file storing the quiz scores,
+/// "MEMO_QUIZ_FILE" or ".rust_memo_quiz" into the home folder
+fn scores_path() -> PathBuf {
+
+    if let Some(path) = env::var_os("MEMO_QUIZ_FILE") {
+        return PathBuf::from(path);
+    }
+
+    env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(".rust_memo_quiz")
+}
+
+/// Prints the quiz score of every topic
+fn quiz_scores() -> io::Result<()> {
+
+    let scores = Scores::load(&scores_path())?;
+    println!("{} sessions", scores.session);
+
+    for topic in TOPICS {
+        if let Some(score) = scores.topics.get(topic.name) {
+            println!(
+                "{:<30} {:>3} correct {:>3} wrong  box {}/{}",
+                topic.name,
+                score.correct,
+                score.wrong,
+                score.level,
+                quiz::LAST_BOX,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Asks the questions of the given topic,
+/// or of every topic due for this session, the weakest first
+fn quiz(name: Option<&str>) -> io::Result<()> {
+
+    let path = scores_path();
+    let mut scores = Scores::load(&path)?;
+    scores.session += 1;
+
+    let mut selected: Vec<&Topic> = match name {
+        Some(name) => vec![find_topic(name)],
+        None => TOPICS
+            .iter()
+            .filter(|topic| scores.is_due(topic.name))
+            .collect(),
+    };
+    selected.sort_by_key(|topic| scores.topics.get(topic.name).map_or(0, |score| score.level));
+
+    let mut topics: Vec<(&'static str, Vec<Question>)> = Vec::new();
+    for topic in selected {
+        let source = fs::read_to_string(topic.directory().join("src/lib.rs"))?;
+        let questions = quiz::questions(topic.name, &source);
+        if !questions.is_empty() {
+            topics.push((topic.name, questions));
+        }
+    }
+
+    if topics.is_empty() {
+        println!("nothing to review for this session");
+    } else {
+        quiz::ask(&topics, &mut scores, &mut io::stdin().lock(), &mut io::stdout())?;
+    }
+
+    scores.save(&path)?;
+    io::stdout().flush()
+}
+
+/// Exits with the error of a failed command
+fn exit_on_error(result: io::Result<()>) {
+
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn main() {
+
+    let arguments: Vec<String> = env::args().skip(1).collect();
+
+    match arguments.as_slice() {
+        [] => list(),
+        [command] if command == "list" => list(),
+
+        /* only the output of the example, without the README */
+        [command, name] if command == "run" => (find_topic(name).run)(),
+
+        [command] if command == "quiz" => exit_on_error(quiz(None)),
+        /* a flag, it cannot be the name of a topic */
+        [command, argument] if command == "quiz" && argument == "--scores" => {
+            exit_on_error(quiz_scores())
+        }
+        [command, name] if command == "quiz" => exit_on_error(quiz(Some(name))),
+
+        [name] => show(find_topic(name)),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+}
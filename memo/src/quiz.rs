@@ -0,0 +1,404 @@
+/* questions generated from the annotations of the examples:
+   "does this line compile?" from the documented error lines
+   and from the valid lines written before them,
+   "what does this print?" from the documented outputs;
+
+   the score of every topic is stored into a local file,
+   topics are scheduled with Leitner boxes: a wrong answer
+   puts the topic back into the first box (asked every session),
+   a perfect session moves it to the next box (asked less often) */
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{
+    self,
+    BufRead,
+    Write,
+};
+use std::path::Path;
+
+use crate::annotations;
+use crate::compile_errors::{
+    self,
+    Verdict,
+};
+use crate::literate;
+
+/// Highest Leitner box, the topic is asked every 2^(box - 1) sessions
+pub const LAST_BOX: u32 = 5;
+
+/// Expected answer of a question
+pub enum Answer {
+
+    /// Whether the marked lines compile
+    Compiles(bool),
+
+    /// What the marked line prints
+    Prints(String),
+}
+
+pub struct Question {
+
+    pub topic: &'static str,
+
+    /// Code displayed with the question, the asked lines start with ">>"
+    pub code: Vec<String>,
+
+    pub answer: Answer,
+
+    /// Displayed after the answer
+    pub explanation: String,
+}
+
+impl Question {
+
+    /// Returns the question text
+    pub fn prompt(&self) -> &'static str {
+
+        match self.answer {
+            Answer::Compiles(_) => "Do the marked lines compile? [y/n]",
+            Answer::Prints(_) => "What does the marked line print?",
+        }
+    }
+
+    /// Returns true if the reply is the expected answer
+    pub fn is_correct(&self, reply: &str) -> bool {
+
+        let reply = reply.trim();
+
+        match &self.answer {
+            Answer::Compiles(compiles) => match reply.to_lowercase().as_str() {
+                "y" | "yes" => *compiles,
+                "n" | "no" => !*compiles,
+                _ => false,
+            },
+            Answer::Prints(output) => reply == output,
+        }
+    }
+}
+
+/// Returns the code of the section containing the given lines,
+/// comments removed (they would give the answer),
+/// the asked lines marked with ">>"
+fn question_code(
+    source: &str,
+    first: usize,
+    last: usize,
+) -> Vec<String>
+{
+    let sections = literate::sections(source);
+    let Some(section) = sections
+        .iter()
+        .find(|section| (section.line..section.line + section.code.len()).contains(&first))
+    else {
+        return Vec::new();
+    };
+
+    section
+        .code
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, line)| {
+            let index = section.line + offset;
+            let code = annotations::without_comment(line)?;
+
+            let marker = if (first..=last).contains(&index) {
+                ">>"
+            } else {
+                "  "
+            };
+            Some(format!("{} {}", marker, code).trim_end().to_string())
+        })
+        .collect()
+}
+
+/// Returns the index of the last statement before the given line,
+/// into the same section (the explained code around a documented error);
+/// the attributes and the lines opening or closing a block are skipped, they ask nothing
+fn previous_code_line(
+    source: &str,
+    line: usize,
+) -> Option<usize>
+{
+    let sections = literate::sections(source);
+    let section = sections
+        .iter()
+        .find(|section| (section.line..section.line + section.code.len()).contains(&line))?;
+
+    (section.line..line).rev().find(|index| {
+        let code = &section.code[index - section.line];
+        annotations::without_comment(code)
+            .map(str::trim)
+            .is_some_and(|code| {
+                !code.is_empty() && !code.starts_with(['}', '#']) && !code.ends_with('{')
+            })
+    })
+}
+
+/// Returns every question about the source of the topic, in the order of the source:
+/// a documented error comes with a question about the valid line before it,
+/// so both answers of "do the marked lines compile?" occur
+pub fn questions(
+    topic: &'static str,
+    source: &str,
+) -> Vec<Question>
+{
+    /* every question with the first line it asks about */
+    let mut questions: Vec<(usize, Question)> = Vec::new();
+    let mut valid_lines = Vec::new();
+
+    let comments = annotations::error_comments(source);
+    for (comment, error) in comments.iter().zip(compile_errors::of_topic(topic)) {
+
+        /* the asked code is displayed as it would be written */
+        let restored = annotations::restore(source, comment, error.code, error.splice);
+        let last = comment.line + error.code.lines().count() - 1;
+
+        /* the comment without the code is the reason of the error */
+        let first_line = error.code.lines().next().unwrap_or_default();
+        let reason = comment.text.replace(first_line, "");
+        let reason = reason.trim().trim_end_matches(':');
+
+        let (compiles, explanation) = match error.verdict {
+            Verdict::Error(code) if reason.is_empty() => (false, format!("error[{}]", code)),
+            Verdict::Error(code) => (false, format!("error[{}]: {}", code, reason)),
+            Verdict::SyntaxError if reason.is_empty() => (false, "syntax error".to_string()),
+            Verdict::SyntaxError => (false, format!("syntax error: {}", reason)),
+        };
+
+        questions.push((
+            comment.line,
+            Question {
+                topic,
+                code: question_code(&restored, comment.line, last),
+                answer: Answer::Compiles(compiles),
+                explanation,
+            },
+        ));
+
+        /* two errors can follow the same valid line, it is asked once */
+        if let Some(line) = previous_code_line(source, comment.line) {
+            if !valid_lines.contains(&line) {
+                valid_lines.push(line);
+                questions.push((
+                    line,
+                    Question {
+                        topic,
+                        code: question_code(source, line, line),
+                        answer: Answer::Compiles(true),
+                        explanation: "it compiles".to_string(),
+                    },
+                ));
+            }
+        }
+    }
+
+    for comment in annotations::output_comments(source) {
+        questions.push((
+            comment.line,
+            Question {
+                topic,
+                code: question_code(source, comment.line, comment.line),
+                explanation: format!("it prints {}", comment.output),
+                answer: Answer::Prints(comment.output),
+            },
+        ));
+    }
+
+    questions.sort_by_key(|(line, _)| *line);
+    questions.into_iter().map(|(_, question)| question).collect()
+}
+
+/// Score and schedule of one topic
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TopicScore {
+
+    pub correct: u32,
+
+    pub wrong: u32,
+
+    /// Leitner box, from 1 to LAST_BOX, 0 if never asked
+    pub level: u32,
+
+    /// Session of the last time the topic was asked
+    pub last_session: u32,
+}
+
+/// Scores of every topic, stored as lines of text:
+/// "session <number>" then "<topic> <correct> <wrong> <box> <last session>"
+#[derive(Debug, Default, PartialEq)]
+pub struct Scores {
+
+    /// Amount of quiz sessions so far
+    pub session: u32,
+
+    pub topics: BTreeMap<String, TopicScore>,
+}
+
+impl Scores {
+
+    /// Parses the content of a scores file, malformed lines are ignored,
+    /// a box after the last one is read as the last one
+    pub fn parse(content: &str) -> Scores {
+
+        let mut scores = Scores::default();
+
+        for line in content.lines() {
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["session", session] => scores.session = session.parse().unwrap_or(0),
+                [topic, correct, wrong, level, last_session] => {
+                    let parsed = (
+                        correct.parse(),
+                        wrong.parse(),
+                        level.parse(),
+                        last_session.parse(),
+                    );
+                    if let (Ok(correct), Ok(wrong), Ok(level), Ok(last_session)) = parsed {
+                        scores.topics.insert(
+                            topic.to_string(),
+                            TopicScore {
+                                correct,
+                                wrong,
+                                level: u32::min(level, LAST_BOX),
+                                last_session,
+                            },
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        scores
+    }
+
+    /// Returns the content of the scores file
+    pub fn serialize(&self) -> String {
+
+        let mut content = format!("session {}\n", self.session);
+
+        for (topic, score) in &self.topics {
+            content.push_str(&format!(
+                "{} {} {} {} {}\n",
+                topic,
+                score.correct,
+                score.wrong,
+                score.level,
+                score.last_session,
+            ));
+        }
+
+        content
+    }
+
+    /// Loads the scores file, a missing file means no score yet
+    pub fn load(path: &Path) -> io::Result<Scores> {
+
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Scores::parse(&content)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Scores::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(
+        &self,
+        path: &Path,
+    ) -> io::Result<()>
+    {
+        fs::write(path, self.serialize())
+    }
+
+    /// Returns true if the topic has to be asked during the current session
+    pub fn is_due(&self, topic: &str) -> bool {
+
+        match self.topics.get(topic) {
+            Some(score) if score.level > 0 => {
+                /* the file may have been edited: no overflow */
+                let interval = 1u32.checked_shl(score.level - 1).unwrap_or(u32::MAX);
+                self.session >= score.last_session.saturating_add(interval)
+            }
+            _ => true,
+        }
+    }
+
+    /// Records the answers of a topic for the current session
+    pub fn record(
+        &mut self,
+        topic: &str,
+        correct: u32,
+        wrong: u32,
+    ) {
+        let session = self.session;
+        let score = self.topics.entry(topic.to_string()).or_default();
+
+        score.correct = score.correct.saturating_add(correct);
+        score.wrong = score.wrong.saturating_add(wrong);
+        score.last_session = session;
+        score.level = if wrong > 0 {
+            1
+        } else {
+            (score.level + 1).min(LAST_BOX)
+        };
+    }
+}
+
+/// Asks the questions of every topic, topic by topic,
+/// records the answers into the scores;
+/// stops at the end of the input
+pub fn ask<R: BufRead, W: Write>(
+    topics: &[(&'static str, Vec<Question>)],
+    scores: &mut Scores,
+    input: &mut R,
+    output: &mut W,
+) -> io::Result<()>
+{
+    let mut total = 0;
+    let mut total_correct = 0;
+    let mut finished = false;
+
+    for (topic, questions) in topics {
+
+        let mut correct = 0;
+        let mut wrong = 0;
+
+        for question in questions {
+
+            writeln!(output, "\n[{}]", topic)?;
+            for line in &question.code {
+                writeln!(output, "{}", line)?;
+            }
+            write!(output, "{} ", question.prompt())?;
+            output.flush()?;
+
+            let mut reply = String::new();
+            if input.read_line(&mut reply)? == 0 {
+                finished = true;
+                break;
+            }
+
+            if question.is_correct(&reply) {
+                correct += 1;
+                writeln!(output, "correct, {}", question.explanation)?;
+            } else {
+                wrong += 1;
+                writeln!(output, "wrong, {}", question.explanation)?;
+            }
+        }
+
+        if correct + wrong > 0 {
+            scores.record(topic, correct, wrong);
+        }
+
+        total += correct + wrong;
+        total_correct += correct;
+
+        if finished {
+            break;
+        }
+    }
+
+    writeln!(output, "\n{}/{} correct answers", total_correct, total)
+}
@@ -0,0 +1,217 @@
+/* the README is embedded into the binary,
+   so the runner works from any directory */
+pub const README: &str = include_str!("../../README.md");
+
+/// Returns the level and the title of a markdown heading line
+pub fn parse_heading(line: &str) -> Option<(usize, &str)> {
+
+    let level = line.chars().take_while(|character| *character == '#').count();
+    if level == 0 {
+        return None;
+    }
+
+    line[level..].strip_prefix(' ').map(|title| (level, title.trim()))
+}
+
+/// Returns the README section with the given title,
+/// from its heading until the next heading of the same or a higher level
+pub fn section<'a>(
+    readme: &'a str,
+    title: &str,
+) -> Option<Vec<&'a str>>
+{
+    let mut lines: Vec<&str> = Vec::new();
+    let mut section_level = None;
+
+    /* "#" lines into code blocks are not headings */
+    let mut in_code_block = false;
+
+    for line in readme.lines() {
+
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+
+        let heading = if in_code_block {
+            None
+        } else {
+            parse_heading(line)
+        };
+
+        match (section_level, heading) {
+            (None, Some((level, heading_title))) if heading_title == title => {
+                section_level = Some(level);
+            }
+            (Some(current), Some((level, _))) if level <= current => break,
+            _ => {}
+        }
+
+        if section_level.is_some() {
+            lines.push(line);
+        }
+    }
+
+    section_level.map(|_| lines)
+}
+
+/// A heading of the README
+pub struct Heading<'a> {
+
+    /// Index of the line into the README
+    pub line: usize,
+
+    pub level: usize,
+
+    pub title: &'a str,
+}
+
+/// A fenced code block of the README
+pub struct CodeBlock<'a> {
+
+    /// Index of the first line of code into the README
+    pub line: usize,
+
+    /// Language following the opening fence, empty if none
+    pub language: &'a str,
+
+    pub lines: Vec<&'a str>,
+}
+
+/// Returns every heading of the README, code blocks excluded
+pub fn headings(readme: &str) -> Vec<Heading<'_>> {
+
+    let mut headings = Vec::new();
+    let mut in_code_block = false;
+
+    for (index, line) in readme.lines().enumerate() {
+
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            continue;
+        }
+
+        if let Some((level, title)) = parse_heading(line) {
+            headings.push(Heading {
+                line: index,
+                level,
+                title,
+            });
+        }
+    }
+
+    headings
+}
+
+/// Returns every fenced code block of the README
+pub fn code_blocks(readme: &str) -> Vec<CodeBlock<'_>> {
+
+    let mut blocks = Vec::new();
+    let mut current: Option<CodeBlock> = None;
+
+    for (index, line) in readme.lines().enumerate() {
+
+        let Some(fence) = line.strip_prefix("```") else {
+            if let Some(block) = current.as_mut() {
+                block.lines.push(line);
+            }
+            continue;
+        };
+
+        match current.take() {
+            Some(block) => blocks.push(block),
+            None => {
+                current = Some(CodeBlock {
+                    line: index + 1,
+                    language: fence.trim(),
+                    lines: Vec::new(),
+                });
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Returns the anchor generated for a heading title:
+/// lowercase, spaces replaced by hyphens, punctuation removed
+pub fn anchor(title: &str) -> String {
+
+    title
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|character| match character {
+            ' ' => Some('-'),
+            '-' | '_' => Some(character),
+            _ if character.is_alphanumeric() => Some(character),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the line index and the target
+/// of every internal link ("[text](#anchor)") of the README
+pub fn internal_links(readme: &str) -> Vec<(usize, &str)> {
+
+    let mut links = Vec::new();
+    let mut in_code_block = false;
+
+    for (index, line) in readme.lines().enumerate() {
+
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+
+        if in_code_block {
+            continue;
+        }
+
+        for (position, _) in line.match_indices("](#") {
+            let target = &line[position + 3..];
+            if let Some(end) = target.find(')') {
+                links.push((index, &target[..end]));
+            }
+        }
+    }
+
+    links
+}
+
+/// Returns the items of the bullet list following the given label
+/// (for instance "To remember:"), items written on several lines are joined
+pub fn bullet_list(
+    lines: &[&str],
+    label: &str,
+) -> Vec<String>
+{
+    let mut items: Vec<String> = Vec::new();
+
+    let Some(start) = lines.iter().position(|line| line.trim() == label) else {
+        return items;
+    };
+
+    for line in &lines[start + 1..] {
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            break;
+        }
+
+        match trimmed.strip_prefix("* ") {
+            Some(item) => items.push(item.to_string()),
+            None => match items.last_mut() {
+                Some(item) => {
+                    item.push(' ');
+                    item.push_str(trimmed);
+                }
+                None => break,
+            },
+        }
+    }
+
+    items
+}
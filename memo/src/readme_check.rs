@@ -0,0 +1,254 @@
+/* checks that the README and the example projects are synchronized:
+   every project has a section, every anchor of the table of contents
+   leads to a heading, and the code snippets match the sources */
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+use std::fs;
+use std::path::Path;
+
+use crate::readme;
+use crate::topics::{
+    Topic,
+    TOPICS,
+};
+
+/// Line written just before a snippet of the README that illustrates
+/// without coming from the sources, the snippet is not compared to them
+pub const ILLUSTRATION_MARKER: &str = "<!-- readme_check: illustration -->";
+
+/* crates of the repository that are tools, not examples */
+const TOOLS: &[&str] = &[
+    "alloc_counter",
+    "memo",
+];
+
+/// Returns the name of every project folder of the repository
+pub fn project_folders(root: &Path) -> Vec<String> {
+
+    let mut folders: Vec<String> = fs::read_dir(root)
+        .expect("the repository cannot be read")
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.join("Cargo.toml").is_file() && path.join("src/lib.rs").is_file())
+        .filter_map(|path| path.file_name()?.to_str().map(String::from))
+        .filter(|name| !TOOLS.contains(&name.as_str()))
+        .collect();
+
+    folders.sort();
+    folders
+}
+
+/// Returns the project referred by a "Check the project `name`" line
+fn referred_project(line: &str) -> Option<&str> {
+
+    let trimmed = line.trim_start().to_lowercase();
+    if !trimmed.starts_with("check") && !trimmed.starts_with("(check") {
+        return None;
+    }
+
+    let start = line.find('`')? + 1;
+    let end = start + line[start..].find('`')?;
+    Some(&line[start..end])
+}
+
+/// Reports the projects without section and the sections of unknown projects
+fn check_projects(
+    readme: &str,
+    folders: &[String],
+    issues: &mut Vec<String>,
+) {
+    let referred: Vec<(usize, &str)> = readme
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| referred_project(line).map(|name| (index, name)))
+        .collect();
+
+    for (index, name) in &referred {
+        if !folders.iter().any(|folder| folder == name) {
+            issues.push(format!("README.md:{}: the project `{}` does not exist", index + 1, name));
+        }
+    }
+
+    for folder in folders {
+        if !referred.iter().any(|(_, name)| name == folder) {
+            issues.push(format!("{}: no README section refers to the project", folder));
+        }
+    }
+
+    for topic in TOPICS {
+        if readme::section(readme, topic.title).is_none() {
+            issues.push(format!("{}: no README section \"{}\"", topic.name, topic.title));
+        }
+    }
+}
+
+/// Reports the internal links without matching heading
+fn check_anchors(
+    readme: &str,
+    issues: &mut Vec<String>,
+) {
+    /* identical headings get a "-1", "-2"... suffix */
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    let mut anchors = HashSet::new();
+
+    for heading in readme::headings(readme) {
+
+        let anchor = readme::anchor(heading.title);
+        let count = occurrences.entry(anchor.clone()).or_insert(0);
+
+        if *count == 0 {
+            anchors.insert(anchor);
+        } else {
+            anchors.insert(format!("{}-{}", anchor, count));
+        }
+        *count += 1;
+    }
+
+    for (index, target) in readme::internal_links(readme) {
+        if !anchors.contains(target) {
+            issues.push(format!("README.md:{}: dead anchor #{}", index + 1, target));
+        }
+    }
+}
+
+/// Returns the lines of code without comments and repeated whitespaces,
+/// with their index; lines without code worth comparing are skipped
+fn code_lines<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<(usize, String)> {
+
+    let mut code_lines = Vec::new();
+    let mut in_comment = false;
+
+    for (index, line) in lines.enumerate() {
+
+        let mut code = line;
+
+        if in_comment {
+            match code.find("*/") {
+                Some(end) => {
+                    code = &code[end + 2..];
+                    in_comment = false;
+                }
+                None => continue,
+            }
+        }
+
+        if let Some(start) = code.find("/*") {
+            in_comment = !code[start..].contains("*/");
+            code = &code[..start];
+        }
+
+        if let Some(start) = code.find("//") {
+            code = &code[..start];
+        }
+
+        let code = code.split_whitespace().collect::<Vec<_>>().join(" ");
+        let is_noise = code.chars().all(|character| "{}()[];,.".contains(character));
+
+        if !is_noise {
+            code_lines.push((index, code));
+        }
+    }
+
+    code_lines
+}
+
+/// Returns the topic whose section contains the given README line
+fn enclosing_topic(
+    headings: &[readme::Heading],
+    line: usize,
+) -> Option<&'static Topic> {
+
+    let mut current: Option<(&Topic, usize)> = None;
+
+    for heading in headings.iter().take_while(|heading| heading.line < line) {
+
+        if let Some(topic) = TOPICS.iter().find(|topic| topic.title == heading.title) {
+            current = Some((topic, heading.level));
+        } else if let Some((_, level)) = current {
+            if heading.level <= level {
+                current = None;
+            }
+        }
+    }
+
+    current.map(|(topic, _)| topic)
+}
+
+/// Reports the snippets mostly found into the source of their topic
+/// but with some lines that are not there anymore;
+/// a snippet with only a few lines of the source, or marked as an illustration,
+/// is written for the README only, it is not compared
+fn check_snippets(
+    root: &Path,
+    readme: &str,
+    issues: &mut Vec<String>,
+) {
+    let headings = readme::headings(readme);
+    let lines: Vec<&str> = readme.lines().collect();
+
+    for block in readme::code_blocks(readme) {
+
+        /* the marker is the line before the opening fence */
+        let is_illustration = block
+            .line
+            .checked_sub(2)
+            .is_some_and(|marker| lines[marker].trim() == ILLUSTRATION_MARKER);
+
+        if block.language != "rust" || is_illustration {
+            continue;
+        }
+
+        let Some(topic) = enclosing_topic(&headings, block.line) else {
+            continue;
+        };
+
+        let Ok(source) = fs::read_to_string(root.join(topic.name).join("src/lib.rs")) else {
+            continue;
+        };
+        let source_lines: HashSet<String> = code_lines(source.lines())
+            .into_iter()
+            .map(|(_, code)| code)
+            .collect();
+
+        let snippet_lines = code_lines(block.lines.iter().copied());
+        let drifted: Vec<&(usize, String)> = snippet_lines
+            .iter()
+            .filter(|(_, code)| !source_lines.contains(code))
+            .collect();
+
+        let found = snippet_lines.len() - drifted.len();
+        if drifted.is_empty() || found < drifted.len() {
+            continue;
+        }
+
+        let mut issue = format!(
+            "README.md:{}: snippet drifted from {}/src/lib.rs ({} of {} lines found)",
+            block.line,
+            topic.name,
+            found,
+            snippet_lines.len(),
+        );
+
+        for (offset, code) in drifted {
+            issue.push_str(&format!("\n    README.md:{}: {}", block.line + offset + 1, code));
+        }
+
+        issues.push(issue);
+    }
+}
+
+/// Returns the issues of the README of the repository, empty if synchronized
+pub fn check(
+    root: &Path,
+    readme: &str,
+) -> Vec<String>
+{
+    let mut issues = Vec::new();
+    check_projects(readme, &project_folders(root), &mut issues);
+    check_anchors(readme, &mut issues);
+    check_snippets(root, readme, &mut issues);
+    issues
+}
@@ -0,0 +1,154 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+/// One example project of the memo
+pub struct Topic {
+
+    /// Name of the project folder
+    pub name: &'static str,
+
+    /// Title of the matching README section
+    pub title: &'static str,
+
+    /// Entry point of the example
+    pub run: fn(),
+}
+
+/* every example project, in the README order */
+pub const TOPICS: &[Topic] = &[
+    Topic {
+        name: "variables_and_mutability",
+        title: "Variables and mutability",
+        run: variables_and_mutability::run,
+    },
+    Topic {
+        name: "constants",
+        title: "Constants",
+        run: constants::run,
+    },
+    Topic {
+        name: "shadowing",
+        title: "Shadowing",
+        run: shadowing::run,
+    },
+    Topic {
+        name: "scalar_types",
+        title: "Scalar types",
+        run: scalar_types::run,
+    },
+    Topic {
+        name: "compound_types",
+        title: "Compound types",
+        run: compound_types::run,
+    },
+    Topic {
+        name: "expressions_and_statements",
+        title: "Statements and expressions",
+        run: expressions_and_statements::run,
+    },
+    Topic {
+        name: "ownership",
+        title: "Ownership",
+        run: ownership::run,
+    },
+    Topic {
+        name: "references",
+        title: "References",
+        run: references::run,
+    },
+    Topic {
+        name: "lifetimes",
+        title: "Lifetimes",
+        run: lifetimes::run,
+    },
+    Topic {
+        name: "const_vs_static",
+        title: "`const` vs `static`",
+        run: const_vs_static::run,
+    },
+    Topic {
+        name: "generic_types",
+        title: "Generic types",
+        run: generic_types::run,
+    },
+    Topic {
+        name: "trait_bounds",
+        title: "Trait bounds",
+        run: trait_bounds::run,
+    },
+    Topic {
+        name: "closures",
+        title: "Closures",
+        run: closures::run,
+    },
+    Topic {
+        name: "box_pointer",
+        title: "`Box<T>` stores data on the heap",
+        run: box_pointer::run,
+    },
+    Topic {
+        name: "deref",
+        title: "`Deref`",
+        run: deref::run,
+    },
+    Topic {
+        name: "drop",
+        title: "`Drop`",
+        run: drop::run,
+    },
+    Topic {
+        name: "rc",
+        title: "`Rc`",
+        run: rc::run,
+    },
+    Topic {
+        name: "threads",
+        title: "Threads",
+        run: threads::run,
+    },
+    Topic {
+        name: "messaging",
+        title: "Messaging",
+        run: messaging::run,
+    },
+    Topic {
+        name: "mutex",
+        title: "Mutex",
+        run: mutex::run,
+    },
+    Topic {
+        name: "macros",
+        title: "Macros",
+        run: macros::run,
+    },
+    Topic {
+        name: "refcell",
+        title: "`Cell` and `RefCell`",
+        run: refcell::run,
+    },
+    Topic {
+        name: "replace",
+        title: "`std::mem::replace`",
+        run: replace::run,
+    },
+];
+
+/// Returns the topic with the given project folder name
+pub fn find(name: &str) -> Option<&'static Topic> {
+    TOPICS.iter().find(|topic| topic.name == name)
+}
+
+impl Topic {
+
+    /// Returns the folder of the project into the repository
+    pub fn directory(&self) -> PathBuf {
+        repository().join(self.name)
+    }
+}
+
+/// Returns the root folder of the repository
+pub fn repository() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
@@ -0,0 +1,93 @@
+use memo::anki::{
+    self,
+    Card,
+};
+
+const SOURCE: &str = "\
+/* a <b>value</b> is printed */
+let value = 10;
+println!(\"{} // {}\", value, \"http://localhost\"); // 10 // http://localhost
+";
+
+#[test]
+fn fields_cannot_break_the_tab_separated_format() {
+
+    assert_eq!(anki::field("a\tb\r\nc"), "a b <br>c");
+    assert_eq!(anki::field("<code>x</code>"), "<code>x</code>");
+}
+
+#[test]
+fn the_deck_starts_with_the_anki_headers() {
+
+    let cards = [Card {
+        front: "front\tside".to_string(),
+        back: "back\nside".to_string(),
+        tag: "mutex",
+    }];
+
+    assert_eq!(
+        anki::deck(&cards),
+        "#separator:tab\n#html:true\n#deck:rust-memo\n#tags column:3\nfront side\tback<br>side\tmutex\n",
+    );
+}
+
+#[test]
+fn lists_of_the_readme_become_cards() {
+
+    let readme = "\
+## `Mutex`
+
+To remember:
+* a lock at a time,
+  then released
+* `Arc` to share it
+
+Benefits:
+* no data race
+
+## Macros
+
+To remember:
+* not this one
+";
+    let cards = anki::list_cards(readme, "mutex", "`Mutex`");
+
+    assert_eq!(cards.len(), 2);
+    assert_eq!(cards[0].front, "<b><code>Mutex</code></b><br>What is there to remember?");
+    assert_eq!(cards[0].back, "<ul><li>a lock at a time, then released</li><li><code>Arc</code> to share it</li></ul>");
+    assert_eq!(cards[1].front, "<b><code>Mutex</code></b><br>What are the benefits?");
+    assert_eq!(cards[1].back, "<ul><li>no data race</li></ul>");
+    assert_eq!(cards[0].tag, "mutex");
+
+    assert!(anki::list_cards(readme, "rc", "`Rc`").is_empty());
+}
+
+#[test]
+fn explained_code_becomes_a_card() {
+
+    let cards = anki::section_cards("mutex", SOURCE);
+
+    assert_eq!(cards.len(), 1);
+    assert_eq!(
+        cards[0].front,
+        "<pre>let value = 10;<br>println!(&quot;{} // {}&quot;, value, &quot;http://localhost&quot;);</pre>\
+         What does this code show?",
+    );
+
+    /* the string literal is not a comment, the comment is the annotation */
+    assert_eq!(
+        cards[0].back,
+        "a &lt;b&gt;value&lt;/b&gt; is printed<br><code>10 // http://localhost</code>",
+    );
+}
+
+#[test]
+fn questions_become_cards() {
+
+    let cards = anki::question_cards("mutex", SOURCE);
+
+    assert_eq!(cards.len(), 1);
+    assert!(cards[0].front.ends_with("</pre>What does the marked line print?"));
+    assert!(cards[0].front.contains("&gt;&gt; println!(&quot;{} // {}&quot;, value, &quot;http://localhost&quot;);"));
+    assert_eq!(cards[0].back, "it prints 10 // http://localhost");
+}
@@ -0,0 +1,241 @@
+/* restores every documented error line of the examples
+   and checks what the compiler really says about it */
+
+use std::env;
+use std::fs;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::process::Command;
+
+use memo::annotations::{
+    self,
+    ErrorComment,
+};
+use memo::compile_errors::{
+    self,
+    KnownError,
+    Verdict,
+};
+use memo::topics::{
+    Topic,
+    TOPICS,
+};
+
+/// Copies the sources of the topic into the given folder,
+/// so modules declared by the library are found by the compiler
+fn copy_sources(
+    source: &Path,
+    destination: &Path,
+) {
+    fs::create_dir_all(destination).unwrap();
+
+    for entry in fs::read_dir(source).unwrap() {
+        let path = entry.unwrap().path();
+        let target = destination.join(path.file_name().unwrap());
+
+        if path.is_dir() {
+            copy_sources(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+/// Returns the names of the crates the topic depends on,
+/// read from the "[dependencies]" table of its manifest
+fn dependencies(topic: &Topic) -> Vec<String> {
+
+    let manifest = fs::read_to_string(topic.directory().join("Cargo.toml")).unwrap();
+
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[dependencies]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| line.split_once('='))
+        .map(|(name, _)| name.trim().to_string())
+        .collect()
+}
+
+/// Returns the strings of an array field of a JSON message,
+/// enough for the file names printed by Cargo
+fn string_array<'a>(
+    message: &'a str,
+    field: &str,
+) -> Option<Vec<&'a str>>
+{
+    let key = format!("\"{}\":[", field);
+    let start = message.find(&key)? + key.len();
+    let end = start + message[start..].find(']')?;
+
+    Some(
+        message[start..end]
+            .split(',')
+            .map(|item| item.trim_matches('"'))
+            .collect(),
+    )
+}
+
+/// Returns the library of the dependency, as reported by Cargo:
+/// a build of the dependency lists the files of its artifacts
+fn library(name: &str) -> PathBuf {
+
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["build", "--quiet", "--message-format=json", "--package", name])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("cargo cannot be started");
+
+    assert!(
+        output.status.success(),
+        "{} cannot be built:\n{}",
+        name,
+        String::from_utf8_lossy(&output.stderr),
+    );
+
+    let target = format!("\"name\":\"{}\"", name);
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|message| message.contains("\"reason\":\"compiler-artifact\"") && message.contains(&target))
+        .filter_map(|message| string_array(message, "filenames"))
+        .flatten()
+        .find(|file| file.ends_with(".rlib"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| panic!("cargo does not report the library of {}", name))
+}
+
+/// Returns the "--extern" arguments of the dependencies of the topic
+fn externs(topic: &Topic) -> Vec<String> {
+
+    dependencies(topic)
+        .iter()
+        .map(|dependency| format!("{}={}", dependency, library(dependency).display()))
+        .collect()
+}
+
+/// Compiles the library with the error line restored,
+/// returns the compiler status and its error output
+fn compile(
+    topic: &Topic,
+    comment: &ErrorComment,
+    error: &KnownError,
+    externs: &[String],
+) -> (bool, String)
+{
+    let sources = topic.directory().join("src");
+    let original = fs::read_to_string(sources.join("lib.rs")).unwrap();
+
+    let folder = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("compile_fail")
+        .join(format!("{}_{}", topic.name, comment.line + 1));
+    copy_sources(&sources, &folder);
+
+    let restored = annotations::restore(
+        &original,
+        comment,
+        error.code,
+        error.splice,
+    );
+    fs::write(folder.join("lib.rs"), restored).unwrap();
+
+    let mut command = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()));
+    command
+        .args(["--edition", "2021", "--crate-type", "lib", "--emit", "metadata"])
+        .args(["--cap-lints", "allow"])
+        .args(["--crate-name", topic.name]);
+
+    for argument in externs {
+        command.arg("--extern").arg(argument);
+    }
+
+    let output = command
+        .arg("--out-dir")
+        .arg(&folder)
+        .arg(folder.join("lib.rs"))
+        .output()
+        .expect("rustc cannot be started");
+
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+/// Returns a description of the mismatch between
+/// the compiler output and the expected verdict
+fn check(
+    verdict: &Verdict,
+    success: bool,
+    stderr: &str,
+) -> Option<String>
+{
+    match verdict {
+        Verdict::Error(code) if success => Some(format!("compiles, {} expected", code)),
+        Verdict::Error(code) if !stderr.contains(&format!("error[{}]", code)) => {
+            Some(format!("{} expected, got:\n{}", code, stderr))
+        }
+        Verdict::SyntaxError if success => Some("compiles, syntax error expected".to_string()),
+        Verdict::SyntaxError if stderr.contains("error[E") => {
+            Some(format!("syntax error expected, got:\n{}", stderr))
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn documented_errors_match_the_compiler() {
+
+    let mut failures = Vec::new();
+
+    for topic in TOPICS {
+
+        let source = fs::read_to_string(topic.directory().join("src/lib.rs")).unwrap();
+        let comments = annotations::error_comments(&source);
+        let errors: Vec<&KnownError> = compile_errors::of_topic(topic.name).collect();
+
+        if comments.len() != errors.len() {
+            failures.push(format!(
+                "{}: {} error comments but {} known errors",
+                topic.name,
+                comments.len(),
+                errors.len(),
+            ));
+            continue;
+        }
+
+        let externs = externs(topic);
+
+        for (comment, error) in comments.iter().zip(errors) {
+
+            let location = format!("{}/src/lib.rs:{}", topic.name, comment.line + 1);
+            let first_line = error.code.lines().next().unwrap_or_default();
+
+            if !comment.text.contains(first_line) {
+                failures.push(format!("{}: comment does not contain `{}`", location, first_line));
+                continue;
+            }
+
+            let (success, stderr) = compile(topic, comment, error, &externs);
+            if let Some(mismatch) = check(&error.verdict, success, &stderr) {
+                failures.push(format!("{}: `{}` {}", location, first_line, mismatch));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
+
+#[test]
+fn every_known_error_belongs_to_a_topic() {
+
+    for error in compile_errors::KNOWN_ERRORS {
+        assert!(
+            TOPICS.iter().any(|topic| topic.name == error.topic),
+            "unknown topic {}",
+            error.topic,
+        );
+    }
+}
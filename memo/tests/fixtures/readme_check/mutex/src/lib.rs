@@ -0,0 +1,9 @@
+use std::sync::Mutex;
+
+pub fn run() {
+
+    let mut value = 20;
+    let mutex = Mutex::new(value);
+    let first_lock = mutex.lock().unwrap();
+    println!("{}", first_lock);
+}
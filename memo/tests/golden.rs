@@ -0,0 +1,117 @@
+/* compares the output of every example with the files of "tests/golden";
+   `cargo test -p memo --test golden -- --bless` regenerates them */
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::{
+    self,
+    Command,
+};
+
+use memo::topics::TOPICS;
+
+/* examples running threads print their lines in any order,
+   so their output is compared as a multiset of lines */
+const UNORDERED_TOPICS: &[&str] = &[
+    "messaging",
+    "mutex",
+    "threads",
+];
+
+/// Returns the output of the example
+fn run(topic: &str) -> Result<String, String> {
+
+    let output = Command::new(env!("CARGO_BIN_EXE_memo"))
+        .args(["run", topic])
+        .output()
+        .map_err(|error| error.to_string())?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "exits with {}:\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr),
+        ));
+    }
+
+    String::from_utf8(output.stdout).map_err(|error| error.to_string())
+}
+
+/// Returns a description of the first difference between the outputs
+fn compare(
+    topic: &str,
+    expected: &str,
+    actual: &str,
+) -> Option<String>
+{
+    let mut expected: Vec<&str> = expected.lines().collect();
+    let mut actual: Vec<&str> = actual.lines().collect();
+
+    if UNORDERED_TOPICS.contains(&topic) {
+        expected.sort_unstable();
+        actual.sort_unstable();
+    }
+
+    if expected == actual {
+        return None;
+    }
+
+    let index = expected
+        .iter()
+        .zip(&actual)
+        .position(|(expected_line, actual_line)| expected_line != actual_line)
+        .unwrap_or(expected.len().min(actual.len()));
+
+    Some(format!(
+        "line {}: expected {:?}, got {:?}",
+        index + 1,
+        expected.get(index),
+        actual.get(index),
+    ))
+}
+
+fn main() {
+
+    let bless = env::args().any(|argument| argument == "--bless");
+    let folder = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+
+    let mut failures = Vec::new();
+
+    for topic in TOPICS {
+
+        let path = folder.join(format!("{}.txt", topic.name));
+
+        let actual = match run(topic.name) {
+            Ok(output) => output,
+            Err(error) => {
+                failures.push(format!("{}: {}", topic.name, error));
+                continue;
+            }
+        };
+
+        let difference = match fs::read_to_string(&path) {
+            Ok(expected) => compare(topic.name, &expected, &actual),
+            Err(_) => Some("no golden file, run with --bless".to_string()),
+        };
+
+        /* an unchanged unordered output is not rewritten
+           with another order of its lines */
+        match difference {
+            Some(_) if bless => fs::write(&path, &actual).unwrap(),
+            Some(difference) => failures.push(format!("{}: {}", topic.name, difference)),
+            None => {}
+        }
+    }
+
+    if !failures.is_empty() {
+        eprintln!("{}", failures.join("\n"));
+        process::exit(1);
+    }
+
+    if bless {
+        println!("golden: {} files up to date", TOPICS.len());
+    } else {
+        println!("golden: {} examples match", TOPICS.len());
+    }
+}
@@ -0,0 +1,93 @@
+use memo::html;
+
+#[test]
+fn special_characters_are_escaped() {
+
+    assert_eq!(html::escape("<a href=\"x\">&</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+    assert_eq!(html::inline("Vec<T> & Box<T>"), "Vec&lt;T&gt; &amp; Box&lt;T&gt;");
+}
+
+#[test]
+fn inline_code_is_not_interpreted() {
+
+    assert_eq!(
+        html::inline("a `**Box<T>**` and **bold**"),
+        "a <code>**Box&lt;T&gt;**</code> and <strong>bold</strong>",
+    );
+
+    /* a backquote without its closing one stays a character */
+    assert_eq!(html::inline("a ` alone"), "a ` alone");
+}
+
+#[test]
+fn links_and_images_are_converted() {
+
+    assert_eq!(
+        html::inline("see [the `Box`](#box) ![tree](images/tree.png)"),
+        "see <a href=\"#box\">the <code>Box</code></a> <img src=\"images/tree.png\" alt=\"tree\">",
+    );
+}
+
+#[test]
+fn an_exclamation_mark_alone_is_not_an_image() {
+
+    assert_eq!(html::inline("wow! see [x](y)"), "wow! see <a href=\"y\">x</a>");
+    assert_eq!(html::inline("a !](x)"), "a !](x)");
+}
+
+#[test]
+fn headings_get_an_anchor() {
+
+    assert_eq!(
+        html::markdown(&["## The `Box<T>` pointer", "text"]),
+        "<h2 id=\"the-boxt-pointer\">The <code>Box&lt;T&gt;</code> pointer</h2>\n<p>text</p>\n",
+    );
+}
+
+#[test]
+fn code_fences_keep_their_content() {
+
+    let lines = [
+        "before",
+        "```rust",
+        "let text = \"<b>\"; // **not bold**",
+        "",
+        "# not a heading",
+        "```",
+        "after",
+    ];
+
+    assert_eq!(
+        html::markdown(&lines),
+        "<p>before</p>\n\
+         <pre><code class=\"language-rust\">let text = &quot;&lt;b&gt;&quot;; // **not bold**\n\n# not a heading</code></pre>\n\
+         <p>after</p>\n",
+    );
+}
+
+#[test]
+fn one_line_comments_are_not_displayed() {
+
+    assert_eq!(
+        html::markdown(&["<!-- readme_check: illustration -->", "```rust", "let value = 1;", "```"]),
+        "<pre><code class=\"language-rust\">let value = 1;</code></pre>\n",
+    );
+}
+
+#[test]
+fn a_code_fence_not_closed_goes_until_the_end() {
+
+    assert_eq!(
+        html::markdown(&["```sh", "cargo run"]),
+        "<pre><code class=\"language-sh\">cargo run</code></pre>\n",
+    );
+}
+
+#[test]
+fn nested_lists_are_closed() {
+
+    assert_eq!(
+        html::markdown(&["* first", "  * nested", "* second", "", "text"]),
+        "<ul>\n<li>first<ul>\n<li>nested</li></ul>\n</li>\n<li>second</li></ul>\n<p>text</p>\n",
+    );
+}
@@ -0,0 +1,63 @@
+use memo::literate;
+
+#[test]
+fn explanations_describe_the_following_code() {
+
+    let source = "\
+fn run() {
+
+    /* first explanation */
+    let first = 1;
+
+    /* second explanation
+       on two lines */
+    let second = 2;
+    let third = 3;
+";
+    let sections = literate::sections(source);
+
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].prose, "");
+    assert_eq!(sections[0].code, ["fn run() {"]);
+
+    assert_eq!(sections[1].prose, "first explanation");
+    assert_eq!(sections[1].line, 3);
+    assert_eq!(sections[1].code, ["let first = 1;"]);
+
+    assert_eq!(sections[2].prose, "second explanation on two lines");
+    assert_eq!(sections[2].line, 7);
+    assert_eq!(sections[2].code, ["let second = 2;", "let third = 3;"]);
+}
+
+#[test]
+fn explanations_in_a_row_are_joined() {
+
+    let sections = literate::sections("/* first */\n/* second */\nlet value = 1;\n");
+
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].prose, "first\n\nsecond");
+    assert_eq!(sections[0].code, ["let value = 1;"]);
+}
+
+#[test]
+fn code_after_the_end_of_a_comment_is_kept() {
+
+    let source = [
+        "    /* one line */ let first = 1;",
+        "    let second = 2;",
+        "    /* two",
+        "       lines */ let third = 3;",
+    ]
+    .join("\n");
+    let sections = literate::sections(&source);
+
+    assert_eq!(sections.len(), 2);
+
+    assert_eq!(sections[0].prose, "one line");
+    assert_eq!(sections[0].line, 0);
+    assert_eq!(sections[0].code, ["let first = 1;", "let second = 2;"]);
+
+    assert_eq!(sections[1].prose, "two lines");
+    assert_eq!(sections[1].line, 3);
+    assert_eq!(sections[1].code, ["let third = 3;"]);
+}
@@ -0,0 +1,189 @@
+use std::fs;
+use std::io::Cursor;
+
+use memo::compile_errors;
+use memo::quiz::{
+    self,
+    Answer,
+    Scores,
+    TopicScore,
+};
+use memo::topics::{
+    self,
+    TOPICS,
+};
+
+#[test]
+fn every_documented_error_becomes_a_question() {
+
+    for topic in TOPICS {
+
+        let source = fs::read_to_string(topic.directory().join("src/lib.rs")).unwrap();
+        let compile_questions = quiz::questions(topic.name, &source)
+            .into_iter()
+            .filter(|question| matches!(question.answer, Answer::Compiles(false)))
+            .count();
+
+        assert_eq!(compile_questions, compile_errors::of_topic(topic.name).count());
+    }
+}
+
+#[test]
+fn output_questions_hide_the_answer() {
+
+    let topic = topics::find("rc").unwrap();
+    let source = fs::read_to_string(topic.directory().join("src/lib.rs")).unwrap();
+    let questions = quiz::questions(topic.name, &source);
+
+    assert_eq!(questions.len(), 4);
+    assert!(questions[0].is_correct("1\n"));
+    assert!(!questions[0].is_correct("2"));
+    assert!(questions[3].is_correct("10"));
+
+    for question in &questions {
+        assert!(question.code.iter().all(|line| !line.contains("//")));
+        assert_eq!(question.code.iter().filter(|line| line.starts_with(">>")).count(), 1);
+    }
+}
+
+#[test]
+fn compile_questions_accept_yes_and_no() {
+
+    let topic = topics::find("ownership").unwrap();
+    let source = fs::read_to_string(topic.directory().join("src/lib.rs")).unwrap();
+    let questions = quiz::questions(topic.name, &source);
+    let question = questions
+        .iter()
+        .find(|question| matches!(question.answer, Answer::Compiles(false)))
+        .unwrap();
+
+    assert!(question.is_correct("n"));
+    assert!(question.is_correct("No"));
+    assert!(!question.is_correct("y"));
+    assert!(!question.is_correct("maybe"));
+}
+
+#[test]
+fn compile_questions_have_both_answers() {
+
+    let topic = topics::find("references").unwrap();
+    let source = fs::read_to_string(topic.directory().join("src/lib.rs")).unwrap();
+    let questions = quiz::questions(topic.name, &source);
+
+    let answers = |compiles: bool| {
+        questions
+            .iter()
+            .filter(|question| matches!(question.answer, Answer::Compiles(answer) if answer == compiles))
+            .count()
+    };
+    assert!(answers(true) > 0);
+    assert!(answers(false) > 0);
+
+    /* the valid line asked is the one written before the error */
+    let valid = questions
+        .iter()
+        .find(|question| matches!(question.answer, Answer::Compiles(true)))
+        .unwrap();
+    assert!(valid.is_correct("y"));
+    assert!(valid.code.contains(&">> let reference = &mut value;".to_string()));
+    assert!(valid.code.iter().all(|line| !line.contains("other_reference")));
+}
+
+#[test]
+fn scores_are_stored_as_text() {
+
+    let mut scores = Scores {
+        session: 3,
+        ..Scores::default()
+    };
+    scores.record("rc", 4, 0);
+    scores.record("ownership", 1, 1);
+
+    let parsed = Scores::parse(&scores.serialize());
+    assert_eq!(parsed, scores);
+    assert_eq!(
+        parsed.topics["ownership"],
+        TopicScore {
+            correct: 1,
+            wrong: 1,
+            level: 1,
+            last_session: 3,
+        },
+    );
+}
+
+#[test]
+fn edited_scores_do_not_overflow() {
+
+    let scores = Scores::parse(&format!(
+        "session 10\nrc 1 0 40 {}\nownership 1 0 4294967295 2\ndrop 1 0 7 -1\n",
+        u32::MAX,
+    ));
+
+    /* the boxes are clamped, the invalid session is ignored */
+    assert_eq!(scores.topics["rc"].level, quiz::LAST_BOX);
+    assert_eq!(scores.topics["ownership"].level, quiz::LAST_BOX);
+    assert!(!scores.topics.contains_key("drop"));
+
+    assert!(!scores.is_due("rc"));
+    assert!(!scores.is_due("ownership"));
+
+    /* a box out of the range, set without parsing */
+    let mut scores = scores;
+    scores.topics.get_mut("ownership").unwrap().level = u32::MAX;
+    scores.session = u32::MAX - 1;
+    assert!(!scores.is_due("ownership"));
+}
+
+#[test]
+fn topics_are_scheduled_with_leitner_boxes() {
+
+    let mut scores = Scores::default();
+    assert!(scores.is_due("rc"));
+
+    /* a perfect answer moves the topic into the box 2, asked every 2 sessions */
+    scores.session = 1;
+    scores.record("rc", 4, 0);
+    scores.record("rc", 4, 0);
+    assert_eq!(scores.topics["rc"].level, 2);
+
+    scores.session = 2;
+    assert!(!scores.is_due("rc"));
+    scores.session = 3;
+    assert!(scores.is_due("rc"));
+
+    /* a wrong answer puts the topic back into the first box */
+    scores.record("rc", 3, 1);
+    assert_eq!(scores.topics["rc"].level, 1);
+    scores.session = 4;
+    assert!(scores.is_due("rc"));
+
+    for _ in 0..10 {
+        scores.record("rc", 1, 0);
+    }
+    assert_eq!(scores.topics["rc"].level, quiz::LAST_BOX);
+}
+
+#[test]
+fn answers_are_read_until_the_end_of_the_input() {
+
+    let topic = topics::find("rc").unwrap();
+    let source = fs::read_to_string(topic.directory().join("src/lib.rs")).unwrap();
+    let topics = vec![(topic.name, quiz::questions(topic.name, &source))];
+
+    let mut scores = Scores {
+        session: 1,
+        ..Scores::default()
+    };
+
+    let mut input = Cursor::new("1\n5\n");
+    let mut output = Vec::new();
+    quiz::ask(&topics, &mut scores, &mut input, &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("correct, it prints 1"));
+    assert!(output.contains("wrong, it prints 2"));
+    assert!(output.ends_with("1/2 correct answers\n"));
+    assert_eq!(scores.topics["rc"].correct, 1);
+    assert_eq!(scores.topics["rc"].wrong, 1);
+}
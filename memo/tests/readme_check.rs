@@ -0,0 +1,40 @@
+use std::fs;
+use std::path::Path;
+
+use memo::readme_check;
+use memo::topics;
+
+#[test]
+fn the_readme_is_synchronized() {
+
+    let root = topics::repository();
+    let readme = fs::read_to_string(root.join("README.md")).unwrap();
+    let issues = readme_check::check(&root, &readme);
+
+    assert!(issues.is_empty(), "\n{}", issues.join("\n"));
+}
+
+#[test]
+fn issues_of_a_fixture_readme_are_reported() {
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/readme_check");
+    let readme = fs::read_to_string(root.join("README.md")).unwrap();
+    let issues = readme_check::check(&root, &readme);
+
+    let is_reported = |issue: &str| issues.iter().any(|reported| reported == issue);
+
+    assert!(is_reported("README.md:39: the project `semaphore` does not exist"));
+    assert!(is_reported("README.md:4: dead anchor #condition-variables"));
+    assert!(is_reported(
+        "README.md:20: snippet drifted from mutex/src/lib.rs (3 of 4 lines found)\n    \
+         README.md:23: let first_lock = mutex.lock().expect(\"poisoned\");"
+    ));
+
+    /* the snippet copied from the sources and the illustration are not reported */
+    assert!(!issues.iter().any(|issue| issue.starts_with("README.md:11:") || issue.starts_with("README.md:30:")));
+    assert!(!is_reported("mutex: no README section refers to the project"));
+    assert!(!is_reported("mutex: no README section \"Mutex\""));
+
+    /* the other topics have no section in the fixture */
+    assert!(is_reported("constants: no README section \"Constants\""));
+}
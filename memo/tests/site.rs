@@ -0,0 +1,39 @@
+/* generates the website into a temporary folder
+   and checks every topic has its page */
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use memo::topics::TOPICS;
+
+#[test]
+fn every_topic_has_a_page() {
+
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join("site");
+    let _ = fs::remove_dir_all(&output);
+
+    let status = Command::new(env!("CARGO_BIN_EXE_site"))
+        .arg(&output)
+        .status()
+        .expect("the site generator cannot be started");
+    assert!(status.success());
+
+    let index = fs::read_to_string(output.join("index.html")).unwrap();
+    let search_index = fs::read_to_string(output.join("search-index.js")).unwrap();
+
+    for topic in TOPICS {
+
+        let page_name = format!("{}.html", topic.name);
+        let page = fs::read_to_string(output.join(&page_name)).unwrap();
+
+        assert!(index.contains(&format!("<a href=\"{}\">", page_name)), "{} is not listed", topic.name);
+        assert!(search_index.contains(&format!("\"page\": \"{}\"", page_name)));
+        assert!(page.contains(&format!("<code>{}/src/lib.rs</code>", topic.name)));
+        assert!(page.contains("<div class=\"literate\">"), "{} has no annotated source", topic.name);
+    }
+
+    assert!(output.join("style.css").is_file());
+    assert!(output.join("search.js").is_file());
+    assert!(output.join("images").is_dir());
+}
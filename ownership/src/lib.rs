@@ -0,0 +1,90 @@
+#![allow(unused)]
+
+use alloc_counter::measure;
+
+fn first_function(string: String) {
+
+    println!("first_function: {}", string);
+}
+
+fn second_function(value: i32) {
+
+    println!("second_function: {}", value);
+}
+
+fn third_function() -> String {
+
+    String::from("my string")
+}
+
+pub fn run() {
+
+    /* "value" is the owner of the value 5 */
+    let value = 5;
+
+    /* "text" is the owner of the string,
+       allocated on the heap;
+       this object is automatically
+       destroyed at the end of the scope:
+       7 bytes are allocated, then freed */
+    let (_, allocations) = measure(|| {
+        let text = String::from("my text");
+
+        // leaving scope: text is invalid from here
+    });
+    println!("{}", allocations); // allocations: 1, deallocations: 1, bytes: 7
+
+    /* we move one string allocated on the heap;
+       "text" is not callable anymore once its
+       content has been moved into "other_text" */
+    let text = String::from("my content");
+    let other_text = text;
+    //error: println!("{}", text);
+    println!("{}", other_text);
+
+    /* the move does not copy the heap data: nothing has been allocated,
+       the string is freed once, when "other_text" is dropped */
+    let (_, allocations) = measure(|| drop(other_text));
+    println!("{}", allocations); // allocations: 0, deallocations: 1, bytes: 0
+
+    /* we create a copy "other_text" of the "text" variable;
+       both of the heap and stack memory is copied
+       and a brand new variable is created:
+       the 15 bytes are allocated again */
+    let text = String::from("another_content");
+    let (other_text, allocations) = measure(|| text.clone());
+    println!("{}", allocations); // allocations: 1, deallocations: 0, bytes: 15
+    println!("{}", text); // "text" is still accessible
+
+    /* i32 implements the Copy trait, so it is copied
+       and not moved; it only lives on the stack,
+       so there is no need for such kind of safety */
+    let x = 5;
+    let y = x;
+    println!("{}", x);
+    println!("{}", y);
+
+    /* nothing is allocated on the heap by a copy */
+    let (_, allocations) = measure(|| x);
+    println!("{}", allocations); // allocations: 0, deallocations: 0, bytes: 0
+
+    /* movement also occurs when a heap object
+       is passed to a function; "string" is not
+       callable anymore within this scope
+       after calling "first_function" */
+    let string = String::from("my content");
+    first_function(string);
+    //error: println!("{}", string);
+    
+    /* copy also occurs when a stack object
+       is passed to a function */
+    let value = 10;
+    second_function(value);
+    println!("{}", value);
+
+    /* move occured from within the function,
+       and moved its returned value:
+       the string is allocated once, by the function */
+    let (value, allocations) = measure(third_function);
+    println!("{}", allocations); // allocations: 1, deallocations: 0, bytes: 9
+}